@@ -0,0 +1,183 @@
+use crate::input::INPUT_DIR_ENV;
+use crate::runner::DayResult;
+use crate::LOWER_BOUND;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers for one input.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Accepted answers for the real inputs, keyed like the input files: `05`, or `05-alt`
+/// for a variant.
+///
+/// ```toml
+/// [05]
+/// part1 = "601"
+/// part2 = "367899984917516"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    pub days: BTreeMap<String, PartAnswers>,
+}
+
+/// Answers are kept next to the inputs they belong to: in `$AOC_INPUT_DIR` if that is set,
+/// otherwise in the crate's `input/` directory.
+pub fn default_path() -> PathBuf {
+    match env::var(INPUT_DIR_ENV) {
+        Ok(dir) => Path::new(&dir).join(ANSWERS_FILE),
+        Err(_) => Path::new(env!("CARGO_MANIFEST_DIR")).join("input").join(ANSWERS_FILE),
+    }
+}
+
+pub fn key(day: u8, variant: Option<&str>) -> String {
+    match variant {
+        Some(v) => format!("{:02}-{}", day, v),
+        None => format!("{:02}", day),
+    }
+}
+
+impl Answers {
+    /// Reads the answers file; a missing file has no answers yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?).with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn expected(&self, key: &str, part: u8) -> Option<&str> {
+        let day = self.days.get(key)?;
+        match part {
+            1 => day.part1.as_deref(),
+            _ => day.part2.as_deref(),
+        }
+    }
+
+    /// Accepts every answer in `res` for the input `key`. Refuses, and records nothing, if
+    /// any of them is only a lower bound.
+    pub fn record(&mut self, key: &str, res: &DayResult) -> Result<()> {
+        for (part, mode) in [(1, &res.part1_mode), (2, &res.part2_mode)] {
+            if let Some(mode) = mode.as_deref().filter(|m| m.starts_with(LOWER_BOUND)) {
+                bail!("part {} is only a {}; not recorded", part, mode);
+            }
+        }
+        let day = self.days.entry(key.to_string()).or_default();
+        if res.part1.is_some() {
+            day.part1 = res.part1.clone();
+        }
+        if res.part2.is_some() {
+            day.part2 = res.part2.clone();
+        }
+        Ok(())
+    }
+
+    /// Checks each part of `res` that ran against the accepted answers.
+    pub fn check(&self, key: &str, res: &DayResult) -> Vec<Check> {
+        [(1, &res.part1), (2, &res.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| answer.clone().map(|a| (part, a)))
+            .map(|(part, actual)| {
+                let status = match self.expected(key, part) {
+                    None => Status::Missing,
+                    Some(e) if e == actual => Status::Pass,
+                    Some(e) => Status::Fail(e.to_string()),
+                };
+                Check { day: res.day, part, actual, status }
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    /// The answer differs from the accepted one, which is kept here.
+    Fail(String),
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail(_) => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+/// The outcome of verifying one part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub actual: String,
+    pub status: Status,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(part1: &str, part2: Option<&str>) -> DayResult {
+        DayResult {
+            day: 5,
+            part1: Some(part1.to_string()),
+            part2: part2.map(|s| s.to_string()),
+            ..DayResult::default()
+        }
+    }
+
+    #[test]
+    fn record_and_check() {
+        let mut answers = Answers::default();
+        let statuses = |a: &Answers, r| a.check("05", &r).into_iter().map(|c| c.status).collect::<Vec<_>>();
+        assert_eq!(vec![Status::Missing, Status::Missing], statuses(&answers, result("3", Some("14"))));
+
+        answers.record("05", &result("3", Some("14"))).unwrap();
+        assert_eq!(vec![Status::Pass, Status::Fail("14".into())], statuses(&answers, result("3", Some("15"))));
+        assert_eq!(vec![Status::Pass], statuses(&answers, result("3", None)));
+        assert_eq!(Status::Missing, answers.check("05-alt", &result("3", None))[0].status);
+    }
+
+    #[test]
+    fn lower_bounds_are_not_recorded() {
+        let mut answers = Answers::default();
+        let partial = DayResult { part1_mode: Some("lower bound: 1 of 2 regions timed out".to_string()), ..result("3", None) };
+        let err = answers.record("12", &partial).unwrap_err().to_string();
+        assert_eq!("part 1 is only a lower bound: 1 of 2 regions timed out; not recorded", err);
+        assert_eq!(Answers::default(), answers);
+        let exact = DayResult { part1_mode: Some("exact".to_string()), ..result("3", None) };
+        answers.record("11", &exact).unwrap();
+        assert_eq!(Some("3"), answers.expected("11", 1));
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(&key(5, None), &result("3", Some("14"))).unwrap();
+        answers.record(&key(12, Some("alt")), &result("2", None)).unwrap();
+        let text = toml::to_string(&answers).unwrap();
+        assert_eq!("[05]\npart1 = \"3\"\npart2 = \"14\"\n\n[12-alt]\npart1 = \"2\"\n", text);
+        assert_eq!(answers, toml::from_str(&text).unwrap());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::input::{self, InputSpec};
+use crate::runner::{self, RunOptions, Runner};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default directory benchmark reports are written to.
+pub const RESULTS_DIR: &str = "results";
+
+/// How many times to run each day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm caches and the allocator.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig { warmup: 3, iterations: 20 }
+    }
+}
+
+/// Summary of the timings of one stage, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub p95: u64,
+    pub mean: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ns: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        ns.sort_unstable();
+        if ns.is_empty() {
+            return Stats { min: 0, median: 0, p95: 0, mean: 0 };
+        }
+        // nearest-rank percentiles
+        let rank = |p: usize| ns[(ns.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: ns[0],
+            median: rank(50),
+            p95: rank(95),
+            mean: (ns.iter().map(|&v| v as u128).sum::<u128>() / ns.len() as u128) as u64,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = Duration::from_nanos;
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}  mean {:>10.2?}",
+            d(self.min),
+            d(self.median),
+            d(self.p95),
+            d(self.mean)
+        )
+    }
+}
+
+/// Timings for every stage of one day.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub input: String,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Option<Stats>,
+}
+
+impl DayBench {
+    /// `(stage name, stats)` for each stage that ran.
+    pub fn stages(&self) -> Vec<(&'static str, Stats)> {
+        let mut out = vec![("parse", self.parse), ("part1", self.part1)];
+        out.extend(self.part2.map(|s| ("part2", s)));
+        out
+    }
+}
+
+/// A full benchmark run, as written to the results directory.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// Seconds since the Unix epoch when the run started.
+    pub timestamp: u64,
+    pub warmup: usize,
+    pub iterations: usize,
+    pub days: Vec<DayBench>,
+}
+
+impl BenchReport {
+    pub fn new(config: BenchConfig) -> Self {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        BenchReport { timestamp, warmup: config.warmup, iterations: config.iterations, days: Vec::new() }
+    }
+
+    /// Writes the report as `bench-<timestamp>.json` under `dir` and returns the path. An
+    /// existing report is never overwritten: later runs in the same second get
+    /// `bench-<timestamp>-2.json` and so on.
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        let text = serde_json::to_string_pretty(self)?;
+        let mut n = 1;
+        loop {
+            let name = match n {
+                1 => format!("bench-{}.json", self.timestamp),
+                n => format!("bench-{}-{}.json", self.timestamp, n),
+            };
+            let path = dir.join(name);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    file.write_all(text.as_bytes()).with_context(|| format!("writing {}", path.display()))?;
+                    return Ok(path);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => n += 1,
+                Err(e) => return Err(e).with_context(|| format!("writing {}", path.display())),
+            }
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+}
+
+/// Runs one day `warmup + iterations` times and summarises each stage.
+pub fn bench_day(day: u8, runner: Runner, spec: &InputSpec, config: BenchConfig) -> Result<DayBench> {
+    if config.iterations == 0 {
+        bail!("at least one iteration is needed");
+    }
+    let input = input::resolve(day, spec)?;
+    for _ in 0..config.warmup {
+        runner::run_input(runner, &input, &RunOptions::default())?;
+    }
+    let (mut parse, mut part1, mut part2) = (Vec::new(), Vec::new(), Vec::new());
+    let mut has_part2 = false;
+    for _ in 0..config.iterations {
+        let res = runner::run_input(runner, &input, &RunOptions::default())?;
+        parse.push(res.parse_time);
+        part1.push(res.part1_time);
+        part2.push(res.part2_time);
+        has_part2 = res.part2.is_some();
+    }
+    Ok(DayBench {
+        day,
+        input: input.origin,
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: has_part2.then(|| Stats::from_samples(&part2)),
+    })
+}
+
+/// One stage whose median moved relative to the baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+    /// Whether the slowdown exceeds the threshold.
+    pub regression: bool,
+}
+
+impl Change {
+    /// Relative change of the median, `0.25` meaning 25% slower.
+    pub fn ratio(&self) -> f64 {
+        self.current as f64 / self.baseline.max(1) as f64 - 1.0
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} {:<5}  {:>10.2?} -> {:>10.2?}  {:>+7.1}%{}",
+            self.day,
+            self.stage,
+            Duration::from_nanos(self.baseline),
+            Duration::from_nanos(self.current),
+            self.ratio() * 100.0,
+            if self.regression { "  REGRESSION" } else { "" }
+        )
+    }
+}
+
+/// Compares the median of every stage present in both reports. A stage regresses when it
+/// got slower by more than `threshold` (e.g. `0.10` for 10%).
+pub fn compare(baseline: &BenchReport, current: &BenchReport, threshold: f64) -> Vec<Change> {
+    let mut out = Vec::new();
+    for day in &current.days {
+        let Some(base) = baseline.days.iter().find(|b| b.day == day.day) else { continue };
+        for (stage, stats) in day.stages() {
+            let Some((_, old)) = base.stages().into_iter().find(|(s, _)| *s == stage) else { continue };
+            let mut change =
+                Change { day: day.day, stage, baseline: old.median, current: stats.median, regression: false };
+            change.regression = change.ratio() > threshold;
+            out.push(change);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        let s = Stats::from_samples(&samples);
+        assert_eq!(Stats { min: 1, median: 10, p95: 19, mean: 10 }, s);
+        let one = Stats::from_samples(&[Duration::from_nanos(7)]);
+        assert_eq!(Stats { min: 7, median: 7, p95: 7, mean: 7 }, one);
+    }
+
+    #[test]
+    fn flags_regressions_past_threshold() {
+        let stats = |median| Stats { min: median, median, p95: median, mean: median };
+        let report = |p1, p2| BenchReport {
+            timestamp: 0,
+            warmup: 0,
+            iterations: 1,
+            days: vec![DayBench {
+                day: 3,
+                input: "input/03.txt".to_string(),
+                parse: stats(100),
+                part1: stats(p1),
+                part2: Some(stats(p2)),
+            }],
+        };
+        let changes = compare(&report(1000, 1000), &report(1050, 1200), 0.10);
+        let flagged: Vec<_> = changes.iter().filter(|c| c.regression).map(|c| c.stage).collect();
+        assert_eq!(3, changes.len());
+        assert_eq!(vec!["part2"], flagged);
+
+        let saved = serde_json::to_string(&report(1, 2)).unwrap();
+        assert_eq!(report(1, 2), serde_json::from_str(&saved).unwrap());
+    }
+
+    #[test]
+    fn runs_in_the_same_second_keep_their_reports() {
+        let dir = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let report = |iterations| BenchReport { timestamp: 1733000000, warmup: 0, iterations, days: Vec::new() };
+        let first = report(1).save(&dir).unwrap();
+        let second = report(2).save(&dir).unwrap();
+        assert_eq!(dir.join("bench-1733000000.json"), first);
+        assert_eq!(dir.join("bench-1733000000-2.json"), second);
+        assert_eq!(report(1), BenchReport::load(&first).unwrap());
+        assert_eq!(report(2), BenchReport::load(&second).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,334 @@
+use adv_code_2025::answers::{self, Answers, Status};
+use adv_code_2025::bench::{self, BenchConfig, BenchReport, RESULTS_DIR};
+use adv_code_2025::cli::{self, DayArgs};
+use adv_code_2025::days;
+use adv_code_2025::days::day08::{Clustering, Day08, Wiring};
+use adv_code_2025::gen::{self, GenOptions};
+use adv_code_2025::input::{self, InputSpec};
+use adv_code_2025::normalize::normalize;
+use adv_code_2025::params;
+use adv_code_2025::runner::{parse_days, run_day, run_input, Format, RunOptions};
+use adv_code_2025::scaffold;
+use adv_code_2025::Solution;
+use anyhow::*;
+use std::env;
+use std::path::PathBuf;
+
+const USAGE: &str = "\
+Usage: aoc run <DAYS>|--all [DAY FLAGS]
+       aoc bench <DAYS>|--all [--warmup N] [--iters N] [--results DIR]
+                 [--compare BASELINE.json] [--threshold PCT] [--variant NAME]
+       aoc verify <DAYS>|--all [--record] [--answers FILE] [--variant NAME]
+       aoc new-day <DAY> [--wrapper]
+       aoc gen <DAY> [--seed N] [--size N] [--edge NAME]... [--out PATH]
+       aoc gen --list
+       aoc clusters [INPUT FLAGS] [--after N] [--newick PATH] [--json PATH]
+
+DAYS is a day number (3), a range (1..=12, 1..13) or a comma-separated list of those.
+Inputs are read from input/DD.txt (or input/DD-NAME.txt with --variant); set AOC_INPUT_DIR
+to look in another directory first.
+
+run takes the same flags as a day binary (below), except that an input path or --example
+only works for a single day. With --format json it prints one JSON object per day and line,
+with the fields day, input, part1, part2, mode (part1, part2), details (part1, part2),
+timings (parse_ns, part1_ns, part2_ns) and error.
+Answers are strings; a day that fails has null answers and timings and an error message.
+
+bench runs each day --warmup times untimed (default 3), then --iters times (default 20),
+and reports min/median/p95/mean for parse, part 1 and part 2. The report is saved as JSON
+under --results (default results/). With --compare, medians that got slower than the
+baseline report by more than --threshold percent (default 10) are flagged as regressions.
+
+verify checks every answer against the accepted ones in answers.toml, kept next to the
+inputs (or --answers FILE), and prints a pass/FAIL/missing table. --record stores the
+current answers as accepted, except those that are only a lower bound (a day 12 region that
+timed out), which it refuses.
+
+new-day creates src/days/dayDD.rs (registered in src/days/mod.rs) with an example test,
+the src/bin/DD.rs wrapper, an empty input/DD.txt and an empty examples/DD.txt fixture. It
+refuses to overwrite an existing day; --wrapper only regenerates src/bin/DD.rs for a day
+whose solver already exists.
+
+gen writes a synthetic input for a day to stdout (or --out PATH). The same --seed (default
+0), --size and --edge cases always give the same input; --size defaults to about the size
+of a real input. --list shows what the size counts and which edge cases each day has.
+
+clusters prints Day 8's single-linkage clustering: every merge in the order Kruskal's
+algorithm makes it, with its distance, then the cluster sizes and each point's cluster after
+--after N merges (by default, the merges made by part 1's closest pairs). --newick and
+--json also write the dendrogram to a file. The input and parameters are chosen as for a
+day binary.";
+
+/// Removes `flag VALUE` from `args`, returning the value.
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>> {
+    let Some(i) = args.iter().position(|a| a == flag) else { return Ok(None) };
+    let value = args.get(i + 1).cloned().ok_or_else(|| anyhow!("{} needs a value", flag))?;
+    args.drain(i..i + 2);
+    Ok(Some(value))
+}
+
+fn take_number<T: std::str::FromStr>(args: &mut Vec<String>, flag: &str) -> Result<Option<T>> {
+    take_value(args, flag)?
+        .map(|v| v.parse().map_err(|_| anyhow!("invalid value '{}' for {}", v, flag)))
+        .transpose()
+}
+
+fn select_days(spec: &str) -> Result<Vec<u8>> {
+    match spec {
+        "--all" => Ok(days::ALL.iter().map(|(d, _)| *d).collect()),
+        _ => parse_days(spec),
+    }
+}
+
+fn run(selected: Vec<u8>, spec: &InputSpec, args: &[String]) -> Result<()> {
+    let Some(mut day_args) = DayArgs::parse(args.iter().cloned())? else {
+        println!("{}\n\n{}", USAGE, cli::FLAGS);
+        return Ok(());
+    };
+    if selected.len() > 1 && (day_args.input.path.is_some() || day_args.input.example.is_some()) {
+        bail!("an input path or --example needs a single day");
+    }
+    if spec.variant.is_some() {
+        day_args.input.variant = spec.variant.clone();
+    }
+    cli::set_verbosity(day_args.verbosity);
+    let mut failed = Vec::new();
+    for (i, day) in selected.into_iter().enumerate() {
+        if i > 0 && day_args.format == Format::Text && day_args.verbosity != cli::Verbosity::Quiet {
+            println!();
+        }
+        let runner = days::runner(day).ok_or_else(|| anyhow!("day {} is not implemented", day))?;
+        if let Err(e) = run_day(day, runner, &day_args) {
+            eprintln!("Day {:02} failed: {:#}", day, e);
+            failed.push(day);
+        }
+    }
+    if !failed.is_empty() {
+        bail!("{} day(s) failed: {:?}", failed.len(), failed);
+    }
+    Ok(())
+}
+
+fn bench(selected: Vec<u8>, spec: &InputSpec, args: &mut Vec<String>) -> Result<()> {
+    let defaults = BenchConfig::default();
+    let config = BenchConfig {
+        warmup: take_number(args, "--warmup")?.unwrap_or(defaults.warmup),
+        iterations: take_number(args, "--iters")?.unwrap_or(defaults.iterations),
+    };
+    let results = PathBuf::from(take_value(args, "--results")?.unwrap_or_else(|| RESULTS_DIR.to_string()));
+    let baseline = take_value(args, "--compare")?.map(PathBuf::from);
+    let threshold: f64 = take_number(args, "--threshold")?.unwrap_or(10.0);
+    if let Some(extra) = args.first() {
+        bail!("unexpected argument '{}'", extra);
+    }
+
+    let mut report = BenchReport::new(config);
+    for day in selected {
+        let runner = days::runner(day).ok_or_else(|| anyhow!("day {} is not implemented", day))?;
+        let res = bench::bench_day(day, runner, spec, config).with_context(|| format!("Day {:02}", day))?;
+        println!("Day {:02} ({} runs of {})", day, config.iterations, res.input);
+        for (stage, stats) in res.stages() {
+            println!("  {:<5}  {}", stage, stats);
+        }
+        report.days.push(res);
+    }
+    let path = report.save(&results)?;
+    println!("\nSaved {}", path.display());
+
+    if let Some(baseline) = baseline {
+        let changes = bench::compare(&BenchReport::load(&baseline)?, &report, threshold / 100.0);
+        println!("\nMedians against {}:", baseline.display());
+        for change in &changes {
+            println!("  {}", change);
+        }
+        let regressions = changes.iter().filter(|c| c.regression).count();
+        if regressions > 0 {
+            bail!("{} stage(s) regressed by more than {}%", regressions, threshold);
+        }
+    }
+    Ok(())
+}
+
+fn verify(selected: Vec<u8>, spec: &InputSpec, args: &mut Vec<String>) -> Result<()> {
+    let record = match args.iter().position(|a| a == "--record") {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    };
+    let path = take_value(args, "--answers")?.map_or_else(answers::default_path, PathBuf::from);
+    if let Some(extra) = args.first() {
+        bail!("unexpected argument '{}'", extra);
+    }
+
+    let mut accepted = Answers::load(&path)?;
+    let (mut failed, mut errors, mut refused) = (0, 0, 0);
+    println!("{:<4} {:<5} {:<8} {:<20} Expected", "Day", "Part", "Status", "Answer");
+    for day in selected {
+        let runner = days::runner(day).ok_or_else(|| anyhow!("day {} is not implemented", day))?;
+        let key = answers::key(day, spec.variant.as_deref());
+        let res = match input::resolve(day, spec).and_then(|input| run_input(runner, &input, &RunOptions::default())) {
+            Result::Ok(res) => res,
+            Err(e) => {
+                let first_line = e.to_string().lines().next().unwrap_or_default().to_string();
+                println!("{:02}   {:<5} {:<8} {}", day, "-", "error", first_line);
+                errors += 1;
+                continue;
+            }
+        };
+        for check in accepted.check(&key, &res) {
+            let expected = match &check.status {
+                Status::Fail(e) => e.as_str(),
+                _ => "",
+            };
+            failed += matches!(check.status, Status::Fail(_)) as usize;
+            let row = format!("{:02}   {:<5} {:<8} {:<20} {}", day, check.part, check.status, check.actual, expected);
+            println!("{}", row.trim_end());
+        }
+        if record {
+            if let Err(e) = accepted.record(&key, &res) {
+                println!("{:02}   {:<5} {:<8} {}", day, "-", "refused", e);
+                refused += 1;
+            }
+        }
+    }
+
+    if record {
+        accepted.save(&path)?;
+        println!("\nRecorded answers in {}", path.display());
+        if refused > 0 {
+            bail!("{} day(s) had answers that are only lower bounds and were not recorded", refused);
+        }
+    } else if failed + errors > 0 {
+        bail!("{} answer(s) failed and {} day(s) did not run", failed, errors);
+    }
+    Ok(())
+}
+
+fn new_day(args: &[String]) -> Result<()> {
+    let (day, wrapper_only) = match args {
+        [day] => (day, false),
+        [day, flag] if flag == "--wrapper" => (day, true),
+        _ => bail!("expected `new-day <DAY> [--wrapper]`"),
+    };
+    let day: u8 = day.parse().map_err(|_| anyhow!("invalid day '{}'", day))?;
+    let root = scaffold::crate_root();
+    let written = if wrapper_only {
+        vec![scaffold::regenerate_wrapper(&root, day)?]
+    } else {
+        scaffold::new_day(&root, day)?
+    };
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn generate(args: &mut Vec<String>) -> Result<()> {
+    if args.first().is_some_and(|a| a == "--list") {
+        for g in gen::GENERATORS {
+            println!("{:02}  size: {} (default {})", g.day, g.size, g.default_size);
+            for (name, description) in g.edges {
+                println!("    --edge {:<12} {}", name, description);
+            }
+        }
+        return Ok(());
+    }
+    let mut opts = GenOptions {
+        seed: take_number(args, "--seed")?.unwrap_or(0),
+        size: take_number(args, "--size")?,
+        edges: Vec::new(),
+    };
+    while let Some(edge) = take_value(args, "--edge")? {
+        opts.edges.push(edge);
+    }
+    let out = take_value(args, "--out")?;
+    let day = match args.as_slice() {
+        [day] => day.parse().map_err(|_| anyhow!("invalid day '{}'", day))?,
+        _ => bail!("expected `gen <DAY> [--seed N] [--size N] [--edge NAME]... [--out PATH]`"),
+    };
+    let text = gen::generate(day, &opts)?;
+    match out {
+        Some(path) => std::fs::write(&path, text).with_context(|| format!("writing {}", path))?,
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+fn clusters(args: &mut Vec<String>) -> Result<()> {
+    let after: Option<usize> = take_number(args, "--after")?;
+    let newick = take_value(args, "--newick")?;
+    let json = take_value(args, "--json")?;
+    let Some(day_args) = DayArgs::parse(args.drain(..))? else {
+        println!("{}\n\n{}", USAGE, cli::FLAGS);
+        return Ok(());
+    };
+    let input = input::resolve(8, &day_args.input)?;
+    let wiring: Wiring = params::resolve(8, &day_args.run_options(8)?.params)?;
+    let junctions = Day08::parse_with(&normalize(&input.text, Day08::NORMALIZE), &wiring)
+        .map_err(|e| e.with_file(input.origin.clone()))?;
+    let clustering = Clustering::new(&junctions);
+    let after = after.unwrap_or_else(|| Clustering::part1_merges(&junctions)).min(clustering.merges.len());
+
+    let point = |i: usize| format!("{},{},{}", clustering.points[i][0], clustering.points[i][1], clustering.points[i][2]);
+    println!("{} points from {}", clustering.points.len(), input.origin);
+    println!("\n{:>6} {:>12} {:>6} {:>6} {:>6}  points", "merge", "distance", "left", "right", "size");
+    for (k, m) in clustering.merges.iter().enumerate() {
+        println!("{:>6} {:>12.3} {:>6} {:>6} {:>6}  {} - {}", k + 1, m.distance, m.left, m.right, m.size, point(m.a), point(m.b));
+    }
+    let sizes = clustering.sizes_after(after);
+    let sizes: Vec<String> = sizes.iter().map(|s| s.to_string()).collect();
+    println!("\nAfter {} merges, {} cluster(s) of sizes {}", after, sizes.len(), sizes.join(", "));
+    println!("\n{:>6} {:>24} {:>8}", "point", "position", "cluster");
+    for (i, c) in clustering.clusters_after(after).into_iter().enumerate() {
+        println!("{:>6} {:>24} {:>8}", i, point(i), c);
+    }
+
+    if let Some(path) = newick {
+        std::fs::write(&path, clustering.newick() + "\n").with_context(|| format!("writing {}", path))?;
+        println!("\nwrote {}", path);
+    }
+    if let Some(path) = json {
+        std::fs::write(&path, serde_json::to_string(&clustering)?).with_context(|| format!("writing {}", path))?;
+        println!("\nwrote {}", path);
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let spec = InputSpec { variant: take_value(&mut args, "--variant")?, ..InputSpec::default() };
+    if args.first().map(|c| c.as_str()) == Some("new-day") {
+        return new_day(&args[1..]);
+    }
+    if args.first().map(|c| c.as_str()) == Some("gen") {
+        return generate(&mut args.split_off(1));
+    }
+    if args.first().map(|c| c.as_str()) == Some("clusters") {
+        let mut rest = args.split_off(1);
+        if let Some(variant) = spec.variant {
+            rest.extend(["--variant".to_string(), variant]);
+        }
+        return clusters(&mut rest);
+    }
+    if args.first().is_some_and(|a| a == "--help" || a == "-h") {
+        println!("{}\n\n{}", USAGE, cli::FLAGS);
+        return Ok(());
+    }
+    if args.len() < 2 {
+        eprintln!("{}", USAGE);
+        bail!("expected a command followed by the days");
+    }
+    let command = args.remove(0);
+    let selected = select_days(&args.remove(0))?;
+    match command.as_str() {
+        "run" => run(selected, &spec, &args),
+        "bench" => bench(selected, &spec, &mut args),
+        "verify" => verify(selected, &spec, &mut args),
+        _ => {
+            eprintln!("{}", USAGE);
+            bail!("expected a command followed by the days");
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::input::InputSpec;
+use crate::params;
+use crate::runner::{Format, RunOptions};
+use anyhow::{anyhow, bail, Result};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The flags every day accepts, shared by the day binaries and `aoc run`.
+pub const FLAGS: &str = "\
+Input (default: DD.txt from $AOC_INPUT_DIR, then input/):
+  PATH, --input PATH   read this file instead; `-` reads stdin
+  --example [N]        run on the puzzle's example N (default 1) from examples/
+  --variant NAME       read DD-NAME.txt instead of DD.txt
+
+Parameters (default: the puzzle's; examples use theirs from examples/params.toml):
+  --param NAME=VALUE   change one of the day's puzzle constants, e.g. --param pairs=10
+  --config PATH        read parameters from a TOML file with a [DD] table per day and
+                       [DD-NAME] tables for variants
+
+Output:
+  --part 1|2           run only one part
+  --time               show how long parsing and each part took
+  --format text|json   json prints one object per day with the answers, timings, input and
+                       any error
+  -q, --quiet          print only the answers, one per line
+  -v, --verbose        also print where the input came from and the day's diagnostics
+  -h, --help           show this help";
+
+/// Help for a day binary.
+pub fn help(day: u8) -> String {
+    format!("Usage: {:02} [PATH | -] [OPTIONS]\n\n{}", day, FLAGS)
+}
+
+/// How much a run prints besides its answers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// Sets the verbosity for the whole process, so days can decide what to print.
+pub fn set_verbosity(v: Verbosity) {
+    VERBOSITY.store(v as u8, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> Verbosity {
+    match VERBOSITY.load(Ordering::Relaxed) {
+        0 => Verbosity::Quiet,
+        2 => Verbosity::Verbose,
+        _ => Verbosity::Normal,
+    }
+}
+
+/// Everything [`FLAGS`] selects for one run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    pub input: InputSpec,
+    /// Run only this part; `None` runs both.
+    pub part: Option<u8>,
+    pub time: bool,
+    pub format: Format,
+    pub verbosity: Verbosity,
+    /// `NAME=VALUE` parameter assignments, in order.
+    pub params: Vec<String>,
+    pub config: Option<String>,
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next().ok_or_else(|| anyhow!("{} needs a value", flag))
+}
+
+impl DayArgs {
+    /// Reads the flags in [`FLAGS`]. `None` means help was asked for.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Option<Self>> {
+        let mut out = DayArgs::default();
+        let (mut quiet, mut verbose) = (false, false);
+        let mut args = args.into_iter().peekable();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--input" => out.set_path(value(&mut args, "--input")?)?,
+                "--variant" => out.input.variant = Some(value(&mut args, "--variant")?),
+                "--example" => {
+                    let n = match args.peek().and_then(|a| a.parse().ok()) {
+                        Some(n) => {
+                            args.next();
+                            n
+                        }
+                        None => 1,
+                    };
+                    if n == 0 {
+                        bail!("examples are numbered from 1");
+                    }
+                    out.input.example = Some(n);
+                }
+                "--part" => {
+                    let part = value(&mut args, "--part")?;
+                    out.part = Some(match part.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        _ => bail!("--part must be 1 or 2, got '{}'", part),
+                    });
+                }
+                "--param" => {
+                    let a = value(&mut args, "--param")?;
+                    params::assignment(&a)?;
+                    out.params.push(a);
+                }
+                "--config" => out.config = Some(value(&mut args, "--config")?),
+                "--time" => out.time = true,
+                "--format" => out.format = value(&mut args, "--format")?.parse()?,
+                "-q" | "--quiet" => quiet = true,
+                "-v" | "--verbose" => verbose = true,
+                flag if flag.starts_with('-') && flag != "-" => bail!("unknown option '{}', see --help", flag),
+                _ => out.set_path(arg)?,
+            }
+        }
+        out.verbosity = match (quiet, verbose) {
+            (true, true) => bail!("--quiet and --verbose cannot be combined"),
+            (true, false) => Verbosity::Quiet,
+            (false, true) => Verbosity::Verbose,
+            (false, false) => Verbosity::Normal,
+        };
+        if out.input.path.is_some() && out.input.example.is_some() {
+            bail!("--example cannot be combined with an input path");
+        }
+        Ok(Some(out))
+    }
+
+    /// The part and parameters to run `day` with.
+    pub fn run_options(&self, day: u8) -> Result<RunOptions> {
+        let params = params::overrides(day, &self.input, self.config.as_deref().map(Path::new), &self.params)?;
+        Ok(RunOptions { part: self.part, params })
+    }
+
+    fn set_path(&mut self, path: String) -> Result<()> {
+        if let Some(old) = &self.input.path {
+            bail!("more than one input path: '{}' and '{}'", old, path);
+        }
+        self.input.path = Some(path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<DayArgs>> {
+        DayArgs::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(DayArgs::default(), parse(&[]).unwrap().unwrap());
+        let args = parse(&["--input", "a.txt", "--part", "2", "--time", "--format", "json", "-q"]).unwrap().unwrap();
+        assert_eq!(Some("a.txt".to_string()), args.input.path);
+        assert_eq!((Some(2), true, Format::Json, Verbosity::Quiet), (args.part, args.time, args.format, args.verbosity));
+        assert_eq!(Some("-".to_string()), parse(&["-"]).unwrap().unwrap().input.path);
+        assert_eq!(Some("alt".to_string()), parse(&["--variant", "alt"]).unwrap().unwrap().input.variant);
+        assert_eq!(None, parse(&["--part", "1", "--help"]).unwrap());
+        let args = parse(&["--param", "pairs=10", "--config", "p.toml", "--param", "k=3"]).unwrap().unwrap();
+        assert_eq!((vec!["pairs=10".to_string(), "k=3".to_string()], Some("p.toml".to_string())), (args.params, args.config));
+    }
+
+    #[test]
+    fn example_number_is_optional() {
+        assert_eq!(Some(1), parse(&["--example"]).unwrap().unwrap().input.example);
+        let args = parse(&["--example", "--verbose"]).unwrap().unwrap();
+        assert_eq!((Some(1), Verbosity::Verbose), (args.input.example, args.verbosity));
+        assert_eq!(Some(2), parse(&["--example", "2", "--time"]).unwrap().unwrap().input.example);
+    }
+
+    #[test]
+    fn rejects_conflicts_and_typos() {
+        for bad in [
+            &["a", "b"][..],
+            &["--part", "3"],
+            &["--part"],
+            &["--quiet", "--verbose"],
+            &["--example", "in.txt"],
+            &["--example", "0"],
+            &["--format", "yaml"],
+            &["--tme"],
+            &["--param", "pairs"],
+        ] {
+            assert!(parse(bad).is_err(), "{:?}", bad);
+        }
+    }
+}
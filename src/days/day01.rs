@@ -0,0 +1,163 @@
+use crate::parse::{self, ParseError};
+use crate::Solution;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+pub struct Day01;
+
+/// The safe's dial.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Dial {
+    /// Number of positions, `0..size`.
+    pub size: i64,
+    /// Where the dial points before the first rotation.
+    pub start: i64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial { size: 100, start: 50 }
+    }
+}
+
+impl Dial {
+    fn check(&self) -> Result<()> {
+        if self.size < 1 || !(0..self.size).contains(&self.start) {
+            bail!("a dial of size {} cannot start at {}", self.size, self.start);
+        }
+        Ok(())
+    }
+}
+
+/// The rotations, and the dial they turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rotations {
+    pub dial: Dial,
+    pub turns: Vec<(char, i64)>,
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Rotations;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Dial;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Dial::default())
+    }
+
+    fn parse_with(input: &str, dial: &Dial) -> Result<Self::Input, ParseError> {
+        let mut rotations = Vec::new();
+        for line in input.lines() {
+            let s = line.trim();
+            if s.is_empty() {
+                continue;
+            }
+            let dir = s.chars().next().unwrap();
+            if dir != 'R' && dir != 'L' {
+                return Err(ParseError::at(input, &s[..dir.len_utf8()], format!("unknown direction `{}`", dir)));
+            }
+            let dist: i64 = parse::field(input, &s[1..])?;
+            rotations.push((dir, dist));
+        }
+        Ok(Rotations { dial: *dial, turns: rotations })
+    }
+
+    fn part1(rotations: &Self::Input) -> Result<usize> {
+        rotations.dial.check()?;
+        let Dial { size, start } = rotations.dial;
+        let mut pos: i64 = start;
+        let mut count: usize = 0;
+        for &(dir, dist) in &rotations.turns {
+            if dir == 'R' {
+                pos = (pos + dist) % size;
+            } else {
+                pos = ((pos - dist) % size + size) % size;
+            }
+            if pos == 0 {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(rotations: &Self::Input) -> Result<usize> {
+        rotations.dial.check()?;
+        let Dial { size, start } = rotations.dial;
+        let mut pos: i64 = start;
+        let mut total: usize = 0;
+        for &(dir, dist) in &rotations.turns {
+            if dir == 'R' {
+                let mut k0 = (size - pos) % size;
+                if k0 == 0 {
+                    k0 = size;
+                }
+                if k0 <= dist {
+                    total += ((dist - k0) / size + 1) as usize;
+                }
+                pos = (pos + dist) % size;
+            } else {
+                let mut k0 = pos % size;
+                if k0 == 0 {
+                    k0 = size;
+                }
+                if k0 <= dist {
+                    total += ((dist - k0) / size + 1) as usize;
+                }
+                pos = ((pos - dist) % size + size) % size;
+            }
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day01>().unwrap();
+    }
+
+    /// Part 2 by turning the dial one click at a time.
+    fn clicks_on_zero(rotations: &Rotations) -> usize {
+        let Dial { size, start } = rotations.dial;
+        let (mut pos, mut count) = (start, 0);
+        for &(dir, dist) in &rotations.turns {
+            for _ in 0..dist {
+                pos = (pos + if dir == 'R' { 1 } else { size - 1 }) % size;
+                count += usize::from(pos == 0);
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn crossings_match_clicking() {
+        let gen = |rng: &mut Rng, size: usize| {
+            let dial = if rng.chance(0.5) {
+                Dial::default()
+            } else {
+                let size = rng.range(1, 12);
+                Dial { size, start: rng.range(0, size - 1) }
+            };
+            let turns = (0..size).map(|_| (*rng.pick(&['L', 'R']), rng.range(0, 3 * dial.size + 50))).collect();
+            Rotations { dial, turns }
+        };
+        differential(3000, 12, 1, gen, |r| Day01::part2(r).unwrap(), clicks_on_zero).unwrap();
+    }
+
+    #[test]
+    fn dial_must_fit() {
+        assert!(Day01::part1(&Day01::parse_with("R5", &Dial { size: 10, start: 10 }).unwrap()).is_err());
+        assert!(Day01::part2(&Day01::parse_with("R5", &Dial { size: 0, start: 0 }).unwrap()).is_err());
+        assert_eq!(1, Day01::part1(&Day01::parse_with("R5\nL2", &Dial { size: 4, start: 3 }).unwrap()).unwrap());
+    }
+}
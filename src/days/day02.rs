@@ -0,0 +1,134 @@
+use crate::parse::{self, ParseError};
+use crate::{NoParams, Solution};
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub struct Day02;
+
+fn parse_ranges(input: &str) -> Result<Vec<(i128, i128)>, ParseError> {
+    let toks = input
+        .lines()
+        .flat_map(|l| l.split(','))
+        .map(|t| t.trim())
+        .filter(|t| !t.is_empty());
+    let mut ranges = Vec::new();
+    for t in toks {
+        let (a, b) = t.split_once('-').ok_or_else(|| ParseError::at(input, t, "expected a range `A-B`"))?;
+        let ai: i128 = parse::field(input, a)?;
+        let bi: i128 = parse::field(input, b)?;
+        ranges.push((ai, bi));
+    }
+    Ok(ranges)
+}
+
+fn sum_exact_double(a: i128, b: i128) -> i128 {
+    let mut total: i128 = 0;
+    let mut k: u32 = 1;
+    loop {
+        let ten_k = i128::pow(10, k);
+        let denom = ten_k + 1;
+        let m_min = i128::pow(10, k - 1);
+        if m_min * denom > b {
+            break;
+        }
+        let m_low = std::cmp::max(m_min, (a + denom - 1) / denom);
+        let m_high = std::cmp::min(ten_k - 1, b / denom);
+        if m_low <= m_high {
+            let n = m_high - m_low + 1;
+            let sum_m = n * (m_low + m_high) / 2;
+            total += denom * sum_m;
+        }
+        k += 1;
+    }
+    total
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(i128, i128)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<usize> {
+        let mut total: i128 = 0;
+        for &(a, b) in ranges {
+            total += sum_exact_double(a, b);
+        }
+        Ok(total as usize)
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<usize> {
+        let max_b = ranges.iter().map(|(_, b)| *b).max().unwrap_or(0);
+        let max_len = max_b.to_string().len() as u32;
+
+        let mut nums: HashSet<i128> = HashSet::new();
+        for k in 1..=max_len {
+            let ten_k = i128::pow(10, k);
+            let m_min_digit = i128::pow(10, k - 1);
+            let mut rcount: u32 = 2;
+            while k * rcount <= max_len {
+                let pow_kr = i128::pow(10, k * rcount);
+                let denom = (pow_kr - 1) / (ten_k - 1);
+
+                if m_min_digit.checked_mul(denom).is_none_or(|v| v > max_b) {
+                    break;
+                }
+
+                for (a, b) in ranges {
+                    let m_low = std::cmp::max(m_min_digit, (a + denom - 1) / denom);
+                    let m_high = std::cmp::min(ten_k - 1, b / denom);
+                    if m_low <= m_high {
+                        for m in m_low..=m_high {
+                            nums.insert(m * denom);
+                        }
+                    }
+                }
+
+                rcount += 1;
+            }
+        }
+
+        let sum: i128 = nums.into_iter().sum();
+        Ok(sum as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day02>().unwrap();
+    }
+
+    /// Part 1 for one range by looking at every ID in it.
+    fn sum_doubles_by_enumeration(a: i128, b: i128) -> i128 {
+        (a..=b)
+            .filter(|n| {
+                let s = n.to_string();
+                s.len() % 2 == 0 && s[..s.len() / 2] == s[s.len() / 2..]
+            })
+            .sum()
+    }
+
+    #[test]
+    fn series_matches_enumeration() {
+        // sizes are digit counts, so ranges cross every power of ten up to 10^9
+        let gen = |rng: &mut Rng, size: usize| {
+            let a = rng.range(1, 10i64.pow(size as u32)) as i128;
+            (a, a + rng.range(0, 3000) as i128)
+        };
+        differential(3000, 9, 1, gen, |&(a, b)| sum_exact_double(a, b), |&(a, b)| sum_doubles_by_enumeration(a, b))
+            .unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+use crate::parse::{self, ParseError};
+use crate::Solution;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+pub struct Day03;
+
+fn max_subseq_value(s: &str, k: usize) -> i128 {
+    let s = s.trim();
+    if s.is_empty() {
+        return 0;
+    }
+    let digits: Vec<i128> = s.chars().filter_map(|c| c.to_digit(10).map(|d| d as i128)).collect();
+    let n = digits.len();
+    if k == 0 {
+        return 0;
+    }
+    if k >= n {
+        let mut v: i128 = 0;
+        for &d in &digits {
+            v = v * 10 + d;
+        }
+        return v;
+    }
+    let mut res: Vec<i128> = Vec::with_capacity(k);
+    let mut start = 0usize;
+    for remaining in (1..=k).rev() {
+        let end = n - remaining;
+        let mut best_d: i128 = -1;
+        let mut best_pos = start;
+        for (i, &d) in digits.iter().enumerate().take(end + 1).skip(start) {
+            if d > best_d {
+                best_d = d;
+                best_pos = i;
+                if best_d == 9 {
+                    break;
+                }
+            }
+        }
+        res.push(best_d);
+        start = best_pos + 1;
+    }
+    let mut val: i128 = 0;
+    for d in res {
+        val = val * 10 + d;
+    }
+    val
+}
+
+/// How many batteries to turn on in each bank.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Batteries {
+    pub part1: usize,
+    pub part2: usize,
+}
+
+impl Default for Batteries {
+    fn default() -> Self {
+        Batteries { part1: 2, part2: 12 }
+    }
+}
+
+fn total_joltage(banks: &[String], k: usize) -> Result<i128> {
+    // values stay below 10^32, so even a million banks cannot overflow the sum
+    if k > 32 {
+        bail!("at most 32 batteries per bank are supported, got {}", k);
+    }
+    Ok(banks.iter().map(|line| max_subseq_value(line, k)).sum())
+}
+
+pub struct Banks {
+    pub batteries: Batteries,
+    pub banks: Vec<String>,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Banks;
+    type Answer1 = i128;
+    type Answer2 = i128;
+    type Params = Batteries;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Batteries::default())
+    }
+
+    fn parse_with(input: &str, batteries: &Batteries) -> Result<Self::Input, ParseError> {
+        parse::expect_chars(input, "0123456789")?;
+        Ok(Banks { batteries: *batteries, banks: input.lines().map(|l| l.to_string()).collect() })
+    }
+
+    fn part1(input: &Self::Input) -> Result<i128> {
+        total_joltage(&input.banks, input.batteries.part1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i128> {
+        total_joltage(&input.banks, input.batteries.part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day03>().unwrap();
+    }
+
+    /// The largest value over every choice of `k` digits, keeping their order.
+    fn max_subseq_by_search(s: &str, k: usize) -> i128 {
+        let digits: Vec<i128> = s.bytes().map(|b| (b - b'0') as i128).collect();
+        if k >= digits.len() {
+            return digits.iter().fold(0, |v, &d| v * 10 + d);
+        }
+        (0u32..1 << digits.len())
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| (0..digits.len()).filter(|i| mask >> i & 1 == 1).fold(0, |v, i| v * 10 + digits[i]))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn greedy_matches_search() {
+        let gen = |rng: &mut Rng, size: usize| {
+            // few distinct digits, so ties are common
+            let bank: String = (0..size).map(|_| *rng.pick(&['1', '5', '5', '9'])).collect();
+            let k = rng.below(size + 2);
+            (bank, k)
+        };
+        differential(3000, 14, 1, gen, |(s, k)| max_subseq_value(s, *k), |(s, k)| max_subseq_by_search(s, *k)).unwrap();
+    }
+}
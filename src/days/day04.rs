@@ -0,0 +1,96 @@
+use crate::parse::{self, ParseError};
+use crate::{Grid, Point, Solution};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+pub struct Day04;
+
+fn read_grid(input: &str) -> Grid<char> {
+    let rows = input
+        .lines()
+        .map(|l| l.trim_end())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.chars().collect())
+        .collect();
+    Grid::from_rows(rows, '.')
+}
+
+/// What the forklifts can reach.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Forklift {
+    /// A roll with this many neighbouring rolls or more cannot be reached.
+    pub crowded: usize,
+}
+
+impl Default for Forklift {
+    fn default() -> Self {
+        Forklift { crowded: 4 }
+    }
+}
+
+pub struct Rolls {
+    pub forklift: Forklift,
+    pub grid: Grid<char>,
+}
+
+fn accessible(grid: &Grid<char>, p: Point, crowded: usize) -> bool {
+    grid[p] == '@' && grid.neighbours8(p).filter(|&n| grid[n] == '@').count() < crowded
+}
+
+fn count_accessible(grid: &Grid<char>, crowded: usize) -> usize {
+    grid.points().filter(|&p| accessible(grid, p, crowded)).count()
+}
+
+fn simulate_removal(grid: &Grid<char>, crowded: usize) -> usize {
+    let mut grid = grid.clone();
+    let mut removed_total = 0usize;
+    loop {
+        let to_remove: Vec<Point> = grid.points().filter(|&p| accessible(&grid, p, crowded)).collect();
+        if to_remove.is_empty() {
+            break;
+        }
+        for &p in &to_remove {
+            grid[p] = '.';
+        }
+        removed_total += to_remove.len();
+    }
+    removed_total
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Rolls;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = Forklift;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Forklift::default())
+    }
+
+    fn parse_with(input: &str, forklift: &Forklift) -> Result<Self::Input, ParseError> {
+        parse::expect_chars(input, ".@")?;
+        Ok(Rolls { forklift: *forklift, grid: read_grid(input) })
+    }
+
+    fn part1(rolls: &Self::Input) -> Result<usize> {
+        Ok(count_accessible(&rolls.grid, rolls.forklift.crowded))
+    }
+
+    fn part2(rolls: &Self::Input) -> Result<usize> {
+        Ok(simulate_removal(&rolls.grid, rolls.forklift.crowded))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day04>().unwrap();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::parse::{self, ParseError};
+use crate::{IntervalSet, NoParams, Solution};
+use anyhow::Result;
+
+pub struct Day05;
+
+type Range = (i64, i64);
+
+fn parse_input(s: &str) -> Result<(Vec<Range>, Vec<i64>), ParseError> {
+    let parts: Vec<&str> = s.trim().splitn(2, "\n\n").collect();
+    let range_part = parts.first().unwrap_or(&"");
+    let mut ranges = Vec::new();
+    for line in range_part.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (a, b) = line.split_once('-').ok_or_else(|| ParseError::at(s, line, "expected a range `A-B`"))?;
+        let a: i64 = parse::field(s, a)?;
+        let b: i64 = parse::field(s, b)?;
+        ranges.push((a, b));
+    }
+
+    let mut ids = Vec::new();
+    if parts.len() > 1 {
+        for line in parts[1].lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            ids.push(parse::field(s, line)?);
+        }
+    }
+
+    Ok((ranges, ids))
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    /// Fresh ID ranges and the available ingredient IDs.
+    type Input = (IntervalSet<i64>, Vec<i64>);
+    type Answer1 = usize;
+    type Answer2 = u128;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (ranges, ids) = parse_input(input)?;
+        Ok((ranges.into_iter().collect(), ids))
+    }
+
+    fn part1((fresh, ids): &Self::Input) -> Result<usize> {
+        Ok(ids.iter().filter(|&&id| fresh.contains(id)).count())
+    }
+
+    fn part2((fresh, _): &Self::Input) -> Result<u128> {
+        Ok(fresh.total_len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day05>().unwrap();
+    }
+
+    #[test]
+    fn ranges_at_the_top_of_i64() {
+        let max = i64::MAX;
+        let input = Day05::parse(&format!("{}-{}\n0-{}\n\n{}\n", max - 1, max, max - 2, max)).unwrap();
+        assert_eq!(1, Day05::part1(&input).unwrap());
+        assert_eq!(1u128 << 63, Day05::part2(&input).unwrap());
+    }
+}
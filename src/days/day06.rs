@@ -0,0 +1,139 @@
+use crate::parse::{self, ParseError};
+use crate::{Grid, NoParams, Solution};
+use anyhow::Result;
+
+pub struct Day06;
+
+/// One problem on the worksheet: its numbers read along the rows (part 1) and down the
+/// columns from right to left (part 2), and the operator that combines them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    by_rows: Vec<i128>,
+    by_columns: Vec<i128>,
+    op: char,
+}
+
+fn is_space_col(grid: &Grid<char>, col: usize) -> bool {
+    grid.col(col).all(|&ch| ch == ' ')
+}
+
+/// The characters of `line` in columns `cols`, as a slice of it; short lines are cut off.
+fn span(line: &str, cols: std::ops::Range<usize>) -> &str {
+    &line[cols.start.min(line.len())..cols.end.min(line.len())]
+}
+
+/// Splits the sheet into problems: runs of columns separated by all-blank columns, each
+/// with the operator found in the bottom row. Operators only go in the bottom row, and
+/// every problem has exactly one.
+fn parse_input(input: &str) -> Result<Vec<Problem>, ParseError> {
+    parse::expect_chars(input, "0123456789+* ")?;
+    let lines: Vec<&str> = input.lines().collect();
+    let grid = Grid::parse(input);
+    let Some((&bottom, rows)) = lines.split_last() else { return Ok(Vec::new()) };
+    for line in rows {
+        if let Some(i) = line.find(['+', '*']) {
+            return Err(ParseError::at(input, &line[i..i + 1], "operators belong in the bottom row"));
+        }
+    }
+    if let Some(i) = bottom.find(|ch: char| ch.is_ascii_digit()) {
+        return Err(ParseError::at(input, &bottom[i..i + 1], "expected an operator or a space in the bottom row"));
+    }
+
+    let mut problems = Vec::new();
+    let mut col = 0usize;
+    while col < grid.width() {
+        if is_space_col(&grid, col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < grid.width() && !is_space_col(&grid, col) {
+            col += 1;
+        }
+        let under = span(bottom, start..col);
+        let mut ops = under.match_indices(['+', '*']);
+        let Some((_, op)) = ops.next() else {
+            return Err(ParseError::at(input, under, "no operator under this problem"));
+        };
+        if let Some((i, _)) = ops.next() {
+            return Err(ParseError::at(input, &under[i..i + 1], "a second operator under one problem"));
+        }
+
+        let mut by_rows = Vec::new();
+        for line in rows {
+            let number = span(line, start..col).trim();
+            if !number.is_empty() {
+                by_rows.push(parse::field(input, number)?);
+            }
+        }
+        let mut by_columns = Vec::new();
+        for c in (start..col).rev() {
+            let digits: String = grid.col(c).take(rows.len()).filter(|&&ch| ch != ' ').collect();
+            if digits.is_empty() {
+                continue;
+            }
+            let top = rows.iter().map(|line| span(line, c..c + 1)).find(|d| d.trim() != "").unwrap_or_default();
+            let value = digits
+                .parse()
+                .map_err(|e| ParseError::at(input, top, format!("invalid value `{}` down this column: {}", digits, e)))?;
+            by_columns.push(value);
+        }
+        problems.push(Problem { by_rows, by_columns, op: op.chars().next().unwrap() });
+    }
+    Ok(problems)
+}
+
+fn apply(op: char, numbers: &[i128]) -> i128 {
+    match op {
+        '+' => numbers.iter().sum(),
+        '*' => numbers.iter().product(),
+        _ => unreachable!(),
+    }
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Problem>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(problems: &Self::Input) -> Result<i128> {
+        Ok(problems.iter().map(|p| apply(p.op, &p.by_rows)).sum())
+    }
+
+    fn part2(problems: &Self::Input) -> Result<i128> {
+        Ok(problems.iter().map(|p| apply(p.op, &p.by_columns)).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day06>().unwrap();
+    }
+
+    #[test]
+    fn malformed_worksheets() {
+        let err = Day06::parse("12 3\n4 56\n+\n").unwrap_err();
+        assert_eq!((1, 1, 4), (err.line, err.column, err.width));
+        assert_eq!("invalid value `12 3`: invalid digit found in string", err.message);
+        let err = Day06::parse("12  3\n4   56\n    *\n").unwrap_err();
+        assert_eq!((3, 1, 2, "no operator under this problem"), (err.line, err.column, err.width, err.message.as_str()));
+        let err = Day06::parse("12  3\n4+  56\n*   *\n").unwrap_err();
+        assert_eq!((2, 2, "operators belong in the bottom row"), (err.line, err.column, err.message.as_str()));
+        let err = Day06::parse("12 3\n4 56\n*  +\n").unwrap_err();
+        assert_eq!((3, 4, "a second operator under one problem"), (err.line, err.column, err.message.as_str()));
+        let err = Day06::parse("12\n4\n1\n").unwrap_err();
+        assert_eq!((3, 1), (err.line, err.column));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::parse::{self, ParseError};
+use crate::{Grid, NoParams, Solution};
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day07;
+
+/// The manifold's diagram and where the beam enters it.
+pub struct Manifold {
+    grid: Grid<char>,
+    start: (usize, usize),
+}
+
+fn count_splits(grid: &Grid<char>, (start_r, start_c): (usize, usize)) -> usize {
+    let h = grid.height();
+    let w = grid.width();
+
+    let mut active: HashSet<usize> = HashSet::new();
+    if start_r + 1 < h {
+        active.insert(start_c);
+    }
+
+    let mut splits: usize = 0;
+
+    for row in grid.rows().skip(start_r + 1) {
+        let mut current: HashSet<usize> = active.iter().cloned().filter(|&c| c < w).collect();
+        let mut handled: HashSet<usize> = HashSet::new();
+        loop {
+            let split_cols: Vec<usize> = current
+                .iter()
+                .cloned()
+                .filter(|&c| row[c] == '^' && !handled.contains(&c))
+                .collect();
+            if split_cols.is_empty() {
+                break;
+            }
+            for c in &split_cols {
+                handled.insert(*c);
+            }
+            for c in &split_cols {
+                current.remove(c);
+            }
+            splits += split_cols.len();
+            for c in split_cols {
+                if c > 0 {
+                    let left = c - 1;
+                    if left < w && !handled.contains(&left) {
+                        current.insert(left);
+                    }
+                }
+                let right = c + 1;
+                if right < w && !handled.contains(&right) {
+                    current.insert(right);
+                }
+            }
+        }
+
+        active = current.into_iter().filter(|&c| c < w).collect();
+    }
+
+    splits
+}
+
+fn count_timelines(grid: &Grid<char>, (sr, sc): (usize, usize)) -> u128 {
+    let h = grid.height();
+    let w = grid.width();
+
+    let mut counts: HashMap<usize, u128> = HashMap::new();
+    if sr + 1 < h {
+        counts.insert(sc, 1u128);
+    }
+
+    for row in grid.rows().skip(sr + 1) {
+        let mut curr: HashMap<usize, u128> = counts
+            .iter()
+            .filter(|(&c, &n)| c < w && n > 0)
+            .map(|(&c, &n)| (c, n))
+            .collect();
+
+        loop {
+            let split_cols: Vec<usize> = curr
+                .iter()
+                .filter(|(&c, &n)| n > 0 && row[c] == '^')
+                .map(|(&c, _)| c)
+                .collect();
+            if split_cols.is_empty() {
+                break;
+            }
+            let mut new_curr = curr.clone();
+            for c in split_cols {
+                let n = new_curr.remove(&c).unwrap_or(0);
+                if n == 0 {
+                    continue;
+                }
+                if c > 0 {
+                    let left = c - 1;
+                    if left < w {
+                        *new_curr.entry(left).or_insert(0) += n;
+                    }
+                }
+                let right = c + 1;
+                if right < w {
+                    *new_curr.entry(right).or_insert(0) += n;
+                }
+            }
+            curr = new_curr;
+        }
+
+        let mut new_counts: HashMap<usize, u128> = HashMap::new();
+        for (c, n) in curr.into_iter() {
+            if n > 0 {
+                new_counts.insert(c, n);
+            }
+        }
+        counts = new_counts;
+    }
+
+    counts.values().copied().sum()
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+    type Answer1 = usize;
+    type Answer2 = u128;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::expect_chars(input, ".S^ ")?;
+        let grid = Grid::parse(input);
+        let start = grid.find(&'S').ok_or_else(|| ParseError::at(input, input.lines().next().unwrap_or(""), "the manifold has no start `S`"))?;
+        Ok(Manifold { grid, start })
+    }
+
+    fn part1(manifold: &Self::Input) -> Result<usize> {
+        Ok(count_splits(&manifold.grid, manifold.start))
+    }
+
+    fn part2(manifold: &Self::Input) -> Result<u128> {
+        Ok(count_timelines(&manifold.grid, manifold.start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day07>().unwrap();
+    }
+
+    #[test]
+    fn manifold_without_start() {
+        let err = Day07::parse("...\n.^.\n").err().unwrap();
+        assert_eq!("the manifold has no start `S`", err.message);
+        assert_eq!((1, 1, 3), (err.line, err.column, err.width));
+        assert!(Day07::parse("").is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::kdtree::{KdTree, Point3};
+use crate::parse::{self, ParseError};
+use crate::union_find::UnionFind;
+use crate::Solution;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+pub struct Day08;
+
+fn read_points(s: &str) -> Result<Vec<Point3>, ParseError> {
+    let mut pts = Vec::new();
+    for l in s.lines() {
+        let l = l.trim();
+        if l.is_empty() { continue; }
+        let parts: Vec<&str> = l.split(',').map(|p| p.trim()).collect();
+        if parts.len() != 3 {
+            return Err(ParseError::at(s, l, format!("expected 3 coordinates `X,Y,Z`, found {}", parts.len())));
+        }
+        let x = parse::field(s, parts[0])?;
+        let y = parse::field(s, parts[1])?;
+        let z = parse::field(s, parts[2])?;
+        pts.push([x, y, z]);
+    }
+    Ok(pts)
+}
+
+/// Junction boxes, indexed for finding close pairs. Pairs at the same distance are taken
+/// in input order.
+pub struct Junctions {
+    tree: KdTree,
+    wiring: Wiring,
+}
+
+/// How the junction boxes are wired up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Wiring {
+    /// How many of the closest pairs part 1 connects.
+    pub pairs: usize,
+}
+
+impl Default for Wiring {
+    fn default() -> Self {
+        Wiring { pairs: 1000 }
+    }
+}
+
+/// One step of single-linkage clustering: the closest two points in different clusters, and
+/// the clusters they join. Clusters are numbered as in a dendrogram: point `i` starts as
+/// cluster `i`, and the cluster made by the `k`th merge is `points + k`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Merge {
+    pub a: usize,
+    pub b: usize,
+    pub distance: f64,
+    pub dist2: u64,
+    pub left: usize,
+    pub right: usize,
+    /// Points in the joined cluster.
+    pub size: usize,
+}
+
+/// The full single-linkage clustering of the junction boxes: every merge Kruskal's algorithm
+/// makes, in order, until they are all connected.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Clustering {
+    pub points: Vec<Point3>,
+    pub merges: Vec<Merge>,
+}
+
+impl Clustering {
+    pub fn new(input: &Junctions) -> Self {
+        let n = input.tree.len();
+        let mut clusters = UnionFind::new(n);
+        // dendrogram node of each set, by its representative
+        let mut node: Vec<usize> = (0..n).collect();
+        let mut merges = Vec::with_capacity(n.saturating_sub(1));
+        for (dist2, a, b) in input.tree.spanning_tree() {
+            let (left, right) = (node[clusters.find(a)], node[clusters.find(b)]);
+            clusters.union(a, b);
+            node[clusters.find(a)] = n + merges.len();
+            let size = clusters.size(a);
+            merges.push(Merge { a, b, distance: (dist2 as f64).sqrt(), dist2, left, right, size });
+        }
+        Clustering { points: input.tree.points().to_vec(), merges }
+    }
+
+    /// How many merges part 1's closest pairs make; the rest join points already connected.
+    pub fn part1_merges(input: &Junctions) -> usize {
+        let mut clusters = UnionFind::new(input.tree.len());
+        input.tree.closest_pairs(input.wiring.pairs).into_iter().filter(|&(_, i, j)| clusters.union(i, j)).count()
+    }
+
+    fn after(&self, merges: usize) -> UnionFind {
+        let mut clusters = UnionFind::new(self.points.len());
+        for m in self.merges.iter().take(merges) {
+            clusters.union(m.a, m.b);
+        }
+        clusters
+    }
+
+    /// Sizes of the clusters after the first `merges` merges, largest first.
+    pub fn sizes_after(&self, merges: usize) -> Vec<usize> {
+        let mut sizes = self.after(merges).component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// The cluster of every point after the first `merges` merges, named by its smallest point.
+    pub fn clusters_after(&self, merges: usize) -> Vec<usize> {
+        let mut label = vec![0; self.points.len()];
+        for group in self.after(merges).groups() {
+            for &i in &group {
+                label[i] = group[0];
+            }
+        }
+        label
+    }
+
+    /// The dendrogram in Newick format, leaves named by point index and branch lengths in
+    /// distance.
+    pub fn newick(&self) -> String {
+        enum Step {
+            Node(usize, Option<f64>),
+            Text(&'static str),
+            Length(f64),
+        }
+        let n = self.points.len();
+        let height = |node: usize| if node < n { 0.0 } else { self.merges[node - n].distance };
+        let mut out = String::new();
+        let mut todo = match n {
+            0 => vec![Step::Text(";")],
+            _ => vec![Step::Text(";"), Step::Node(n + self.merges.len() - 1, None)],
+        };
+        // the tree is as deep as there are merges, so walk it with a stack
+        while let Some(step) = todo.pop() {
+            match step {
+                Step::Text(t) => out.push_str(t),
+                Step::Length(l) => {
+                    let _ = write!(out, ":{}", l);
+                }
+                Step::Node(id, length) => {
+                    if let Some(l) = length {
+                        todo.push(Step::Length(l));
+                    }
+                    if id < n {
+                        let _ = write!(out, "{}", id);
+                        continue;
+                    }
+                    let m = &self.merges[id - n];
+                    out.push('(');
+                    todo.push(Step::Text(")"));
+                    todo.push(Step::Node(m.right, Some(m.distance - height(m.right))));
+                    todo.push(Step::Text(","));
+                    todo.push(Step::Node(m.left, Some(m.distance - height(m.left))));
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Junctions;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    type Params = Wiring;
+
+    fn parse(input: &str) -> Result<Junctions, ParseError> {
+        Self::parse_with(input, &Wiring::default())
+    }
+
+    fn parse_with(input: &str, wiring: &Wiring) -> Result<Junctions, ParseError> {
+        Ok(Junctions { tree: KdTree::new(read_points(input)?), wiring: *wiring })
+    }
+
+    // Part 1: union the closest `pairs` pairs
+    fn part1(input: &Junctions) -> Result<u128> {
+        let mut clusters = UnionFind::new(input.tree.len());
+        for (_, i, j) in input.tree.closest_pairs(input.wiring.pairs) {
+            clusters.union(i, j);
+        }
+
+        let mut sizes = clusters.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        while sizes.len() < 3 { sizes.push(1); }
+        Ok((sizes[0] as u128) * (sizes[1] as u128) * (sizes[2] as u128))
+    }
+
+    // Part 2: the pair that finally joins everything is the longest edge of the spanning tree
+    fn part2(input: &Junctions) -> Result<u128> {
+        let pts = input.tree.points();
+        Ok(match input.tree.spanning_tree().last() {
+            None => 0u128,
+            Some(&(_, i, j)) => {
+                let xi = pts[i][0] as i128;
+                let xj = pts[j][0] as i128;
+                (xi * xj) as u128
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day08>().unwrap();
+    }
+
+    #[test]
+    fn clustering() {
+        let text = std::fs::read_to_string(fixtures::example_path(8, 1)).unwrap();
+        let input = Day08::parse(&text).unwrap();
+        let clustering = Clustering::new(&input);
+        assert_eq!(19, clustering.merges.len());
+        let last = clustering.merges.last().unwrap();
+        assert_eq!((20, 25272), (last.size, clustering.points[last.a][0] * clustering.points[last.b][0]));
+
+        // the puzzle's ten closest pairs make nine merges
+        let ten = Day08::parse_with(&text, &Wiring { pairs: 10 }).unwrap();
+        let merges = Clustering::part1_merges(&ten);
+        assert_eq!(9, merges);
+        assert_eq!(vec![5, 4, 2, 2, 1, 1, 1, 1, 1, 1, 1], clustering.sizes_after(merges));
+        let labels = clustering.clusters_after(merges);
+        assert!(labels.iter().enumerate().all(|(i, &l)| l <= i && labels[l] == l));
+        let mut sizes: Vec<usize> = (0..20).map(|c| labels.iter().filter(|&&l| l == c).count()).filter(|&k| k > 0).collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        assert_eq!(clustering.sizes_after(merges), sizes);
+    }
+
+    #[test]
+    fn newick() {
+        let input = Day08::parse("0,0,0\n3,0,0\n0,4,0\n").unwrap();
+        assert_eq!("((0:3,1:3):1,2:4);", Clustering::new(&input).newick());
+        assert_eq!("0;", Clustering::new(&Day08::parse("5,5,5\n").unwrap()).newick());
+        assert_eq!(";", Clustering::new(&Day08::parse("").unwrap()).newick());
+    }
+}
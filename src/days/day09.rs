@@ -0,0 +1,239 @@
+use crate::parse::{self, ParseError};
+use crate::{IntervalSet, NoParams, Solution};
+use anyhow::Result;
+
+pub struct Day09;
+
+/// Reads the red tiles, which in order (and back to the first) trace a loop of row and
+/// column edges.
+fn read_points(s: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut pts = Vec::new();
+    let mut lines = Vec::new();
+    for line in s.lines() {
+        let line = line.trim();
+        if line.is_empty() { continue; }
+        let (x, y) = line.split_once(',').ok_or_else(|| ParseError::at(s, line, "expected a point `X,Y`"))?;
+        let x: i64 = parse::field(s, x.trim())?;
+        let y: i64 = parse::field(s, y.trim())?;
+        pts.push((x, y));
+        lines.push(line);
+    }
+    for i in 0..pts.len() {
+        let next = (i + 1) % pts.len();
+        let ((x1, y1), (x2, y2)) = (pts[i], pts[next]);
+        if x1 != x2 && y1 != y2 {
+            let msg = format!("tiles {} and {} are not in the same row or column", lines[i], lines[next]);
+            return Err(ParseError::at(s, lines[i.max(next)], msg));
+        }
+    }
+    Ok(pts)
+}
+
+fn max_area(pts: &[(i64, i64)]) -> i64 {
+    let mut best = 0i64;
+    for (i, &(xi, yi)) in pts.iter().enumerate() {
+        for &(xj, yj) in &pts[i + 1..] {
+            let area = (xi - xj).abs() + 1;
+            let area2 = (yi - yj).abs() + 1;
+            let a = area * area2;
+            if a > best { best = a; }
+        }
+    }
+    best
+}
+
+/// Which tiles of the plane a rectilinear polygon covers (boundary included), on a
+/// coordinate-compressed grid. Compressed index `2k + 1` is the k-th distinct coordinate
+/// and `2k + 2` the open gap after it, so every compressed cell is uniformly in or out.
+/// A 2D prefix sum over the outside cells answers "is this rectangle fully inside" in O(1).
+/// The polygon's edges must be axis-aligned, as `read_points` checks.
+struct InsideTable {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    /// `outside[y * (w + 1) + x]` counts outside cells in compressed rows `< y`, columns `< x`.
+    outside: Vec<u32>,
+    w: usize,
+}
+
+impl InsideTable {
+    fn new(poly: &[(i64, i64)]) -> Self {
+        let mut xs: Vec<i64> = poly.iter().map(|&(x, _)| x).collect();
+        let mut ys: Vec<i64> = poly.iter().map(|&(_, y)| y).collect();
+        xs.sort_unstable();
+        xs.dedup();
+        ys.sort_unstable();
+        ys.dedup();
+        let (w, h) = (2 * xs.len() + 1, 2 * ys.len() + 1);
+
+        let mut verticals = Vec::new();
+        let mut horizontals = Vec::new();
+        for i in 0..poly.len() {
+            let (x1, y1) = poly[i];
+            let (x2, y2) = poly[(i + 1) % poly.len()];
+            if x1 == x2 {
+                verticals.push((x1, y1.min(y2), y1.max(y2)));
+            } else {
+                horizontals.push((y1, x1.min(x2), x1.max(x2)));
+            }
+        }
+
+        // inside tiles of each open band between consecutive ys: even-odd pairs of the
+        // vertical edges crossing it, boundary columns included
+        let bands: Vec<IntervalSet<i64>> = ys
+            .windows(2)
+            .map(|pair| {
+                let mut crossing: Vec<i64> =
+                    verticals.iter().filter(|&&(_, lo, hi)| lo <= pair[0] && hi >= pair[1]).map(|&(x, _, _)| x).collect();
+                crossing.sort_unstable();
+                crossing.chunks_exact(2).map(|c| (c[0], c[1])).collect()
+            })
+            .collect();
+        // a row on a vertex y is inside wherever a neighbouring band is, plus its own edges
+        let rows: Vec<IntervalSet<i64>> = (0..ys.len())
+            .map(|k| {
+                let mut row: IntervalSet<i64> =
+                    horizontals.iter().filter(|&&(y, _, _)| y == ys[k]).map(|&(_, lo, hi)| (lo, hi)).collect();
+                for band in [k.checked_sub(1), Some(k)].into_iter().flatten().filter_map(|b| bands.get(b)) {
+                    row = row.union(band);
+                }
+                row
+            })
+            .collect();
+
+        // a compressed cell is outside if it holds tiles and none of them is inside; the
+        // padding ring always is, and an empty gap between adjacent coordinates never is
+        let gap = |vs: &[i64], c: usize| match c {
+            0 => None,
+            c if c == 2 * vs.len() => None,
+            c => Some((vs[c / 2 - 1] + 1, vs[c / 2] - 1)),
+        };
+        let mut out = vec![true; w * h];
+        for cy in 0..h {
+            let row = if cy % 2 == 1 {
+                &rows[cy / 2]
+            } else {
+                match gap(&ys, cy) {
+                    Some((lo, hi)) if lo > hi => {
+                        out[cy * w..(cy + 1) * w].fill(false);
+                        continue;
+                    }
+                    Some(_) => &bands[cy / 2 - 1],
+                    None => continue,
+                }
+            };
+            for cx in 0..w {
+                out[cy * w + cx] = if cx % 2 == 1 {
+                    !row.contains(xs[cx / 2])
+                } else {
+                    match gap(&xs, cx) {
+                        Some((lo, hi)) => lo <= hi && !row.contains(lo),
+                        None => true,
+                    }
+                };
+            }
+        }
+
+        let mut outside = vec![0u32; (w + 1) * (h + 1)];
+        for cy in 0..h {
+            for cx in 0..w {
+                outside[(cy + 1) * (w + 1) + cx + 1] = out[cy * w + cx] as u32
+                    + outside[cy * (w + 1) + cx + 1]
+                    + outside[(cy + 1) * (w + 1) + cx]
+                    - outside[cy * (w + 1) + cx];
+            }
+        }
+        InsideTable { xs, ys, outside, w }
+    }
+
+    /// Whether every tile of the rectangle spanned by two polygon vertices is inside.
+    fn contains(&self, (xa, ya): (i64, i64), (xb, yb): (i64, i64)) -> bool {
+        let cx = |x: i64| 2 * self.xs.binary_search(&x).unwrap() + 1;
+        let cy = |y: i64| 2 * self.ys.binary_search(&y).unwrap() + 1;
+        let (x1, x2) = (cx(xa.min(xb)), cx(xa.max(xb)) + 1);
+        let (y1, y2) = (cy(ya.min(yb)), cy(ya.max(yb)) + 1);
+        let at = |x: usize, y: usize| self.outside[y * (self.w + 1) + x];
+        at(x2, y2) + at(x1, y1) - at(x1, y2) - at(x2, y1) == 0
+    }
+}
+
+fn max_inside_area(poly: &[(i64, i64)]) -> i64 {
+    let table = InsideTable::new(poly);
+    let mut best2 = 0i64;
+    for (i, &a) in poly.iter().enumerate() {
+        for &b in &poly[i + 1..] {
+            let area = ((a.0 - b.0).abs() + 1) * ((a.1 - b.1).abs() + 1);
+            if area > best2 && table.contains(a, b) {
+                best2 = area;
+            }
+        }
+    }
+    best2
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_points(input)
+    }
+
+    fn part1(pts: &Self::Input) -> Result<i64> {
+        Ok(max_area(pts))
+    }
+
+    fn part2(pts: &Self::Input) -> Result<i64> {
+        Ok(max_inside_area(pts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day09>().unwrap();
+    }
+
+    #[test]
+    fn rectangles_inside_polygon() {
+        let pts = read_points("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n").unwrap();
+        assert_eq!(50, max_area(&pts));
+        assert_eq!(24, max_inside_area(&pts));
+    }
+
+    #[test]
+    fn notch_is_outside() {
+        // a U shape: the gap between the arms must not count as inside
+        let u = [(0, 0), (10, 0), (10, 10), (7, 10), (7, 3), (3, 3), (3, 10), (0, 10)];
+        let table = InsideTable::new(&u);
+        assert!(table.contains((0, 0), (10, 3)));
+        assert!(table.contains((0, 10), (3, 0)));
+        assert!(!table.contains((3, 10), (7, 3)));
+        assert!(!table.contains((0, 10), (10, 0)));
+    }
+
+    #[test]
+    fn adjacent_coordinates_leave_no_gap() {
+        // the notch between x = 3 and x = 4 has no tiles, so the full rectangle is inside
+        let notch = [(0, 0), (3, 0), (3, 2), (4, 2), (4, 0), (7, 0), (7, 5), (0, 5)];
+        assert_eq!(48, max_inside_area(&notch));
+    }
+
+    #[test]
+    fn diagonal_edges() {
+        let err = read_points("0,0\n5,5\n0,5\n").unwrap_err();
+        assert_eq!("tiles 0,0 and 5,5 are not in the same row or column", err.message);
+        assert_eq!((2, 1, 3), (err.line, err.column, err.width));
+        // the edge back to the first tile is checked too
+        let err = read_points("0,0\n5,0\n5,5\n1,5\n").unwrap_err();
+        assert_eq!("tiles 1,5 and 0,0 are not in the same row or column", err.message);
+        assert_eq!(4, err.line);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::cli::{self, Verbosity};
+use crate::gf2::{BitMatrix, BitVec};
+use crate::lp;
+use crate::parse::{self, ParseError};
+use crate::{Answer, NoParams, Solution};
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::fmt;
+
+pub struct Day10;
+
+/// Indicator light pattern, button wirings and (optional) joltage targets.
+type Machine = (String, Vec<Vec<usize>>, Option<Vec<i64>>);
+
+fn parse_input(txt: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut out = Vec::new();
+    let re_br = Regex::new(r"\[([.#]+)\]").expect("valid pattern");
+    let re_par = Regex::new(r"\(([^)]*)\)").expect("valid pattern");
+    let re_cu = Regex::new(r"\{([^}]*)\}").expect("valid pattern");
+
+    for ln in txt.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let cap = re_br
+            .captures(ln)
+            .ok_or_else(|| ParseError::at(txt, ln, "expected an indicator light pattern like `[.##.]`"))?;
+        let pattern = cap[1].to_string();
+        let mut btns = Vec::new();
+        for pc in re_par.captures_iter(ln) {
+            let s = pc.get(1).map_or("", |m| m.as_str().trim());
+            let mut v = Vec::new();
+            for p in s.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+                let light: usize = parse::field(txt, p)?;
+                if light >= pattern.len() {
+                    return Err(ParseError::at(txt, p, format!("button wires light {} but there are only {}", light, pattern.len())));
+                }
+                v.push(light);
+            }
+            btns.push(v);
+        }
+        let jolt = match re_cu.captures(ln).and_then(|c| c.get(1)) {
+            Some(m) => {
+                let v = m.as_str().split(',').map(|p| parse::field(txt, p.trim())).collect::<Result<Vec<i64>, _>>()?;
+                if v.len() != pattern.len() {
+                    let msg = format!("expected {} joltage targets, found {}", pattern.len(), v.len());
+                    return Err(ParseError::at(txt, m.as_str(), msg));
+                }
+                Some(v)
+            }
+            None => None,
+        };
+        out.push((pattern, btns, jolt));
+    }
+    Ok(out)
+}
+
+/// Fewest button presses that leave exactly the `#` lights on: each button toggles its
+/// lights, so this is a minimum-weight solution of a linear system over GF(2).
+fn min_presses_gf2(pattern: &str, btns: &[Vec<usize>]) -> Option<usize> {
+    let n = pattern.len();
+    let wiring = BitMatrix::from_rows(
+        btns.len(),
+        (0..n).map(|light| BitVec::with_ones(btns.len(), (0..btns.len()).filter(|&j| btns[j].contains(&light)))).collect(),
+    );
+    let target = BitVec::with_ones(n, pattern.match_indices('#').map(|(i, _)| i));
+    Some(wiring.solve(&target)?.min_weight().count_ones())
+}
+
+/// Minimum total presses so that every joltage counter reaches its target exactly.
+/// Returns the number of presses per button, or `None` if no non-negative integer solution exists.
+pub fn min_presses_joltage(btns: &[Vec<usize>], target: &[i64]) -> Option<Vec<u64>> {
+    let m = target.len();
+    if btns.iter().flatten().any(|&i| i >= m) || target.iter().any(|&t| t < 0) {
+        return None;
+    }
+    // a button can't be pressed more often than the smallest counter it feeds allows
+    let hi: Vec<i64> = btns.iter().map(|b| b.iter().map(|&i| target[i]).min().unwrap_or(0)).collect();
+    let mut search = JoltageSearch {
+        btns,
+        wiring: (0..m).map(|i| btns.iter().map(|b| b.contains(&i) as u8 as f64).collect()).collect(),
+        target,
+        best: None,
+    };
+    search.branch(vec![0; btns.len()], hi);
+    search.best.map(|(_, presses)| presses.into_iter().map(|p| p as u64).collect())
+}
+
+/// Branch and bound over the press counts. Each node bounds every button's presses to an
+/// interval; the linear relaxation over those intervals gives a lower bound on the total, and
+/// a node whose bound can't beat the best solution so far is dropped. A node whose relaxed
+/// optimum is fractional is split on one of the fractional buttons.
+struct JoltageSearch<'a> {
+    btns: &'a [Vec<usize>],
+    /// `wiring[i][j]` is 1 if button `j` feeds counter `i`.
+    wiring: Vec<Vec<f64>>,
+    target: &'a [i64],
+    best: Option<(i64, Vec<i64>)>,
+}
+
+/// How far from an integer a relaxed value may be and still count as that integer.
+const INTEGRAL: f64 = 1e-6;
+
+impl JoltageSearch<'_> {
+    /// Whether `presses` bring every counter exactly to its target.
+    fn reaches_target(&self, presses: &[i64]) -> bool {
+        let mut counters = vec![0; self.target.len()];
+        for (b, &p) in self.btns.iter().zip(presses) {
+            for &i in b {
+                counters[i] += p;
+            }
+        }
+        counters == self.target
+    }
+
+    fn branch(&mut self, lo: Vec<i64>, hi: Vec<i64>) {
+        let ones = vec![1.0; lo.len()];
+        let target: Vec<f64> = self.target.iter().map(|&t| t as f64).collect();
+        let bounds = |v: &[i64]| v.iter().map(|&x| x as f64).collect::<Vec<_>>();
+        let Some((relaxed, x)) = lp::minimize(&ones, &self.wiring, &target, &bounds(&lo), &bounds(&hi)) else { return };
+        let bound = (relaxed - INTEGRAL).ceil() as i64;
+        if self.best.as_ref().is_some_and(|(b, _)| bound >= *b) {
+            return;
+        }
+        let fractional = |j: usize| (x[j] - x[j].round()).abs() > INTEGRAL;
+        // the most constrained fractional button: fewest values left, then the least settled
+        let split = (0..lo.len())
+            .filter(|&j| fractional(j))
+            .min_by(|&a, &b| (hi[a] - lo[a]).cmp(&(hi[b] - lo[b])).then((x[b] - x[b].round()).abs().total_cmp(&(x[a] - x[a].round()).abs())));
+        let Some(j) = split else {
+            let presses: Vec<i64> = x.iter().map(|v| v.round() as i64).collect();
+            if self.reaches_target(&presses) {
+                self.best = Some((presses.iter().sum(), presses));
+            } else if let Some(j) = (0..lo.len()).filter(|&j| lo[j] < hi[j]).max_by_key(|&j| hi[j] - lo[j]) {
+                // rounding went wrong; fall back to halving the widest interval
+                let mid = (lo[j] + hi[j]).div_euclid(2);
+                self.split(lo, hi, j, mid, true);
+            }
+            return;
+        };
+        let down = x[j].floor() as i64;
+        self.split(lo, hi, j, down, x[j] - (down as f64) < 0.5);
+    }
+
+    /// Branches on `presses[j] <= at` and `presses[j] > at`, the first one first if `low_first`.
+    fn split(&mut self, lo: Vec<i64>, hi: Vec<i64>, j: usize, at: i64, low_first: bool) {
+        let (mut lower_hi, mut upper_lo) = (hi.clone(), lo.clone());
+        lower_hi[j] = at;
+        upper_lo[j] = at + 1;
+        if low_first {
+            self.branch(lo, lower_hi);
+            self.branch(upper_lo, hi);
+        } else {
+            self.branch(upper_lo, hi);
+            self.branch(lo, lower_hi);
+        }
+    }
+}
+
+/// Total presses for part 2 along with the press vector chosen for each machine, which the
+/// JSON report carries as `details`.
+pub struct JoltagePresses {
+    pub total: u64,
+    pub per_machine: Vec<Vec<u64>>,
+}
+
+impl fmt::Display for JoltagePresses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.total)
+    }
+}
+
+impl Answer for JoltagePresses {
+    fn details(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "per_machine": self.per_machine }))
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = JoltagePresses;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<usize> {
+        let mut total1 = 0usize;
+        for (pattern, btns, _) in machines {
+            let res = min_presses_gf2(pattern, btns).ok_or_else(|| anyhow!("no solution"))?;
+            total1 += res;
+        }
+        Ok(total1)
+    }
+
+    fn part2(machines: &Self::Input) -> Result<JoltagePresses> {
+        let mut per_machine = Vec::new();
+        for (idx, (pattern, btns, jolt)) in machines.iter().enumerate() {
+            let target = jolt
+                .as_ref()
+                .ok_or_else(|| anyhow!("machine {} [{}] has no joltage requirements", idx + 1, pattern))?;
+            let presses = min_presses_joltage(btns, target).ok_or_else(|| {
+                anyhow!("machine {} [{}] cannot reach joltage {:?} with non-negative presses", idx + 1, pattern, target)
+            })?;
+            if cli::verbosity() == Verbosity::Verbose {
+                eprintln!("machine {}: presses {:?} = {}", idx + 1, presses, presses.iter().sum::<u64>());
+            }
+            per_machine.push(presses);
+        }
+        let total = per_machine.iter().flatten().sum();
+        Ok(JoltagePresses { total, per_machine })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+    use crate::runner::{solve, Report, RunOptions};
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day10>().unwrap();
+    }
+
+    #[test]
+    fn presses_in_the_json_report() {
+        let text = std::fs::read_to_string(fixtures::example_path(10, 1)).unwrap();
+        let res = solve::<Day10>(&text, &RunOptions::part(2)).unwrap();
+        let report = serde_json::to_value(Report::new(10, None, &Ok(res))).unwrap();
+        let per_machine: Vec<Vec<u64>> = serde_json::from_value(report["details"]["part2"]["per_machine"].clone()).unwrap();
+        let totals: Vec<u64> = per_machine.iter().map(|p| p.iter().sum()).collect();
+        assert_eq!(vec![10, 12, 11], totals);
+        assert_eq!(vec![6, 5, 4], per_machine.iter().map(Vec::len).collect::<Vec<_>>());
+        assert_eq!("33", report["part2"]);
+    }
+
+    fn check(btns: &[Vec<usize>], target: &[i64], expected_total: u64) {
+        let presses = min_presses_joltage(btns, target).unwrap();
+        assert_eq!(expected_total, presses.iter().sum::<u64>());
+        for (i, &t) in target.iter().enumerate() {
+            let got: u64 = btns.iter().zip(&presses).filter(|(b, _)| b.contains(&i)).map(|(_, &p)| p).sum();
+            assert_eq!(t as u64, got, "counter {}", i);
+        }
+    }
+
+    #[test]
+    fn joltage_example_machines() {
+        check(&[vec![3], vec![1, 3], vec![2], vec![2, 3], vec![0, 2], vec![0, 1]], &[3, 5, 4, 7], 10);
+        check(&[vec![0, 2, 3, 4], vec![2, 3], vec![0, 4], vec![0, 1, 2], vec![1, 2, 3, 4]], &[7, 5, 12, 7, 2], 12);
+        check(&[vec![0, 1, 2, 3, 4], vec![0, 3, 4], vec![0, 1, 2, 4, 5], vec![1, 2]], &[10, 11, 11, 5, 10, 5], 11);
+    }
+
+    #[test]
+    fn joltage_unreachable() {
+        // both counters always move together, so they can't end up different
+        assert_eq!(None, min_presses_joltage(&[vec![0, 1]], &[1, 2]));
+        // every press adds 2 to the counters' sum, which has to reach 3
+        assert_eq!(None, min_presses_joltage(&[vec![0, 1], vec![1, 2], vec![0, 2]], &[1, 1, 1]));
+    }
+
+    #[test]
+    fn joltage_with_many_free_buttons() {
+        // 13 buttons on 4 counters leave 9 presses free to choose
+        let btns = [
+            vec![1, 2], vec![0], vec![3], vec![0, 1, 3], vec![1, 3], vec![1, 2, 3], vec![2],
+            vec![0, 1], vec![0, 2], vec![2, 3], vec![0, 1, 2], vec![0, 3], vec![1],
+        ];
+        let start = Instant::now();
+        check(&btns, &[93, 123, 112, 99], 152);
+        assert!(start.elapsed() < Duration::from_secs(2), "took {:?}", start.elapsed());
+    }
+
+    /// Fewest presses by trying every last press, remembering each remaining target.
+    fn presses_by_search(btns: &[Vec<usize>], target: &[i64], memo: &mut HashMap<Vec<i64>, Option<u64>>) -> Option<u64> {
+        if target.iter().all(|&t| t == 0) {
+            return Some(0);
+        }
+        if let Some(&known) = memo.get(target) {
+            return known;
+        }
+        let best = btns
+            .iter()
+            .filter(|b| !b.is_empty() && b.iter().all(|&i| target[i] > 0))
+            .filter_map(|b| {
+                let mut rest = target.to_vec();
+                b.iter().for_each(|&i| rest[i] -= 1);
+                presses_by_search(btns, &rest, memo).map(|p| p + 1)
+            })
+            .min();
+        memo.insert(target.to_vec(), best);
+        best
+    }
+
+    #[test]
+    fn joltage_matches_search() {
+        let gen = |rng: &mut Rng, size: usize| {
+            let counters = 1 + size % 4;
+            let btns: Vec<Vec<usize>> =
+                (0..rng.range(1, 7)).map(|_| (0..counters).filter(|_| rng.chance(0.5)).collect()).collect();
+            // half the targets come from actual presses, so most machines can be solved
+            let mut target: Vec<i64> = (0..counters).map(|_| rng.range(0, 6)).collect();
+            if rng.chance(0.5) {
+                target = vec![0; counters];
+                for b in &btns {
+                    let presses = rng.range(0, 3);
+                    b.iter().for_each(|&i| target[i] += presses);
+                }
+            }
+            (btns, target)
+        };
+        let fast = |(btns, target): &(Vec<Vec<usize>>, Vec<i64>)| min_presses_joltage(btns, target).map(|p| p.iter().sum());
+        let naive = |(btns, target): &(Vec<Vec<usize>>, Vec<i64>)| presses_by_search(btns, target, &mut HashMap::new());
+        differential(2000, 12, 10, gen, fast, naive).unwrap();
+    }
+}
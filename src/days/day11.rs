@@ -0,0 +1,154 @@
+use crate::graph::{Counting, PathCount};
+use crate::parse::ParseError;
+use crate::{Graph, Solution};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+pub struct Day11;
+
+type Count = PathCount;
+
+fn parse_input(text: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let colon = line.find(':').ok_or_else(|| ParseError::at(text, line, "expected `NAME: OUTPUTS...`"))?;
+        let name = line[..colon].trim();
+        if name.is_empty() {
+            return Err(ParseError::at(text, &line[..colon + 1], "missing device name before `:`"));
+        }
+        let from = graph.intern(name);
+        for out in line[colon + 1..].split_whitespace() {
+            let to = graph.intern(out);
+            graph.add_edge(from, to);
+        }
+    }
+    Ok(graph)
+}
+
+/// Paths from device `from` to device `to` through every device in `via`. A device that
+/// is not in the list cannot be on any path, so asking about one gives 0.
+fn count_paths(graph: &Graph, from: &str, to: &str, via: &[&str], counting: Counting) -> Result<Count> {
+    let id = |name: &str| graph.id(name);
+    let (Some(from), Some(to)) = (id(from), id(to)) else { return Ok(counting.zero()) };
+    let Some(via) = via.iter().map(|&name| id(name)).collect::<Option<Vec<_>>>() else { return Ok(counting.zero()) };
+    graph.count_paths(from, to, &via, counting)
+}
+
+/// The devices whose paths are counted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Route {
+    /// Where part 1's paths start.
+    pub part1_from: String,
+    /// Where part 2's paths start.
+    pub part2_from: String,
+    /// Where every path ends.
+    pub to: String,
+    /// Devices every part 2 path has to visit.
+    pub via: Vec<String>,
+    /// Count modulo this, usually a large prime, instead of exactly; 0 counts exactly.
+    pub modulus: u64,
+}
+
+impl Default for Route {
+    fn default() -> Self {
+        Route {
+            part1_from: "you".to_string(),
+            part2_from: "svr".to_string(),
+            to: "out".to_string(),
+            via: vec!["dac".to_string(), "fft".to_string()],
+            modulus: 0,
+        }
+    }
+}
+
+impl Route {
+    fn counting(&self) -> Result<Counting> {
+        match self.modulus {
+            0 => Ok(Counting::Exact),
+            1 => bail!("cannot count modulo 1: every count would be 0"),
+            m => Ok(Counting::Modulo(m)),
+        }
+    }
+}
+
+pub struct Rack {
+    pub graph: Graph,
+    pub route: Route,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Rack;
+    type Answer1 = Count;
+    type Answer2 = Count;
+    type Params = Route;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Route::default())
+    }
+
+    fn parse_with(input: &str, route: &Route) -> Result<Self::Input, ParseError> {
+        Ok(Rack { graph: parse_input(input)?, route: route.clone() })
+    }
+
+    /// Paths from `you` to `out`, unless the route names other devices.
+    fn part1(rack: &Self::Input) -> Result<Count> {
+        count_paths(&rack.graph, &rack.route.part1_from, &rack.route.to, &[], rack.route.counting()?)
+    }
+
+    /// Paths from `svr` to `out` that visit both `dac` and `fft`, unless the route names
+    /// other devices.
+    fn part2(rack: &Self::Input) -> Result<Count> {
+        let via: Vec<&str> = rack.route.via.iter().map(|v| v.as_str()).collect();
+        count_paths(&rack.graph, &rack.route.part2_from, &rack.route.to, &via, rack.route.counting()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::Answer;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day11>().unwrap();
+    }
+
+    #[test]
+    fn any_devices() {
+        let graph = Day11::parse(&std::fs::read_to_string(fixtures::example_path(11, 2)).unwrap()).unwrap().graph;
+        let count = |from, via: &[&str]| count_paths(&graph, from, "out", via, Counting::Exact).unwrap().to_string();
+        assert_eq!("8", count("svr", &[]));
+        assert_eq!("4", count("svr", &["fft"]));
+        assert_eq!("2", count("ccc", &["dac"]));
+        assert_eq!("0", count("svr", &["nope"]));
+    }
+
+    #[test]
+    fn modular_counts() {
+        let text = std::fs::read_to_string(fixtures::example_path(11, 2)).unwrap();
+        let route = |modulus| Route { modulus, ..Route::default() };
+        let part2 = |modulus| Day11::part2(&Day11::parse_with(&text, &route(modulus)).unwrap()).map(|c| (c.to_string(), c.mode()));
+        assert_eq!(("2".to_string(), Some("mod 3".to_string())), part2(3).unwrap());
+        assert_eq!(("0".to_string(), Some("mod 2".to_string())), part2(2).unwrap());
+        assert_eq!(("2".to_string(), Some("exact".to_string())), part2(0).unwrap());
+        assert!(part2(1).is_err());
+    }
+
+    #[test]
+    fn cyclic_racks() {
+        // aaa and bbb feed each other, but only ccc leads on to out
+        let rack = Day11::parse("you: aaa ccc\naaa: bbb\nbbb: aaa ddd\nccc: out\nddd: ddd\n").unwrap();
+        assert_eq!("1", Day11::part1(&rack).unwrap().to_string());
+        let rack = Day11::parse("you: aaa\naaa: bbb out\nbbb: ccc\nccc: aaa\n").unwrap();
+        let err = Day11::part1(&rack).unwrap_err().to_string();
+        assert_eq!("infinitely many paths from you to out: they can go round the cycle aaa -> bbb -> ccc -> aaa", err);
+    }
+}
@@ -0,0 +1,817 @@
+use crate::cli::{self, Verbosity};
+use crate::parse::{self, ParseError};
+use crate::{Answer, Grid, Solution, LOWER_BOUND};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub struct Day12;
+
+type Shape = Vec<(i32, i32)>;
+type Region = (usize, usize, Vec<usize>);
+
+fn parse_input(data: &str) -> Result<(Vec<Grid<char>>, Vec<Region>), ParseError> {
+    let lines: Vec<&str> = data.lines().collect();
+
+    let mut shapes: Vec<Grid<char>> = Vec::new();
+    let mut i = 0usize;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        if line.is_empty() {
+            i += 1;
+            continue;
+        }
+        if line.contains(':') && line.contains('x') {
+            break;
+        }
+        if line.ends_with(':') {
+            i += 1;
+            let mut rows: Vec<Vec<char>> = Vec::new();
+            while i < lines.len() && !lines[i].trim().is_empty() {
+                let row = lines[i].trim_end();
+                if let Some((j, ch)) = row.char_indices().find(|&(_, ch)| ch != '#' && ch != '.') {
+                    let msg = format!("unexpected character `{}` in shape", ch);
+                    return Err(ParseError::at(data, &row[j..j + ch.len_utf8()], msg));
+                }
+                rows.push(lines[i].chars().collect());
+                i += 1;
+            }
+            shapes.push(Grid::from_rows(rows, '.'));
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut regions = Vec::new();
+    while i < lines.len() {
+        let line = lines[i].trim();
+        i += 1;
+        if line.is_empty() { continue; }
+        let (size, rest) = line.split_once(':').ok_or_else(|| ParseError::at(data, line, "expected a region `WxH: COUNTS...`"))?;
+        let (w, h) = size.split_once('x').ok_or_else(|| ParseError::at(data, size, "expected a size `WxH`"))?;
+        let w: usize = parse::field(data, w.trim())?;
+        let h: usize = parse::field(data, h.trim())?;
+        let counts = rest.split_whitespace().map(|s| parse::field(data, s)).collect::<Result<Vec<usize>, _>>()?;
+        if counts.len() > shapes.len() {
+            let msg = format!("{} present counts given but only {} shapes are defined", counts.len(), shapes.len());
+            return Err(ParseError::at(data, rest.trim(), msg));
+        }
+        regions.push((w, h, counts));
+    }
+
+    Ok((shapes, regions))
+}
+
+fn shape_cells(grid: &Grid<char>) -> Shape {
+    grid.points().filter(|&p| grid[p] == '#').map(|(y, x)| (x as i32, y as i32)).collect()
+}
+
+fn normalize(mut cells: Shape) -> Shape {
+    let minx = cells.iter().map(|(x,_)| *x).min().unwrap_or(0);
+    let miny = cells.iter().map(|(_,y)| *y).min().unwrap_or(0);
+    for p in cells.iter_mut() {
+        p.0 -= minx;
+        p.1 -= miny;
+    }
+    cells.sort();
+    cells
+}
+
+fn transforms(cells: &Shape) -> Vec<Shape> {
+    let mut out: HashSet<Shape> = HashSet::new();
+    for &flipx in &[1, -1] {
+        for &flipy in &[1, -1] {
+            for rot in 0..4 {
+                let mut pts = cells.clone();
+                for p in pts.iter_mut() {
+                    p.0 *= flipx;
+                    p.1 *= flipy;
+                }
+                for _ in 0..rot {
+                    pts = pts.into_iter().map(|(x,y)| (-y, x)).collect();
+                }
+                let norm = normalize(pts);
+                out.insert(norm);
+            }
+        }
+    }
+    let mut v: Vec<Shape> = out.into_iter().collect();
+    v.sort_by_key(|a| (a.len(), a.clone()));
+    v
+}
+
+/// How hard the packing engine tries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Packing {
+    /// Milliseconds the exact search may spend on a single region before giving up.
+    pub timeout_ms: u64,
+}
+
+impl Default for Packing {
+    fn default() -> Self {
+        Packing { timeout_ms: 5000 }
+    }
+}
+
+impl Packing {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms)
+    }
+}
+
+/// Occupancy of a region's cells, one bit per cell in row-major order.
+#[derive(Clone, Debug)]
+struct Bitboard {
+    words: Vec<u64>,
+}
+
+impl Bitboard {
+    fn new(cells: usize) -> Self {
+        Bitboard { words: vec![0; cells.div_ceil(64)] }
+    }
+    fn get(&self, i: usize) -> bool {
+        (self.words[i / 64] >> (i % 64)) & 1 == 1
+    }
+    fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1u64 << (i % 64);
+    }
+    fn clear(&mut self, i: usize) {
+        self.words[i / 64] &= !(1u64 << (i % 64));
+    }
+}
+
+/// One way to put a present into the region: which shape, and which cells it covers.
+#[derive(Clone, Debug)]
+struct Placement {
+    shape: usize,
+    cells: Vec<usize>,
+}
+
+/// Which present (by order of placement) covers each cell of a region.
+#[derive(Clone, Debug)]
+pub struct Layout {
+    cells: Grid<Option<usize>>,
+}
+
+impl Layout {
+    fn new(w: usize, h: usize, placements: &[&Placement]) -> Self {
+        let mut cells = Grid::new(w, h, None);
+        for (piece, p) in placements.iter().enumerate() {
+            for &c in &p.cells {
+                cells[(c / w, c % w)] = Some(piece);
+            }
+        }
+        Layout { cells }
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const LABELS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+        let chars = self.cells.map(|c| c.map_or('.', |piece| LABELS[piece % LABELS.len()] as char));
+        write!(f, "{}", chars)
+    }
+}
+
+/// What the packing engine could establish about one region.
+#[derive(Clone, Debug)]
+pub enum PackOutcome {
+    Feasible(Layout),
+    Infeasible(String),
+    TimedOut,
+}
+
+impl PackOutcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PackOutcome::Feasible(_) => "proven-feasible",
+            PackOutcome::Infeasible(_) => "proven-infeasible",
+            PackOutcome::TimedOut => "timed-out",
+        }
+    }
+}
+
+/// Per-region outcomes; displays as the number of regions proven feasible. If any region
+/// timed out, that number is only a lower bound, and the mode says so.
+pub struct PackingReport {
+    pub outcomes: Vec<PackOutcome>,
+}
+
+impl PackingReport {
+    pub fn count(&self, label: &str) -> usize {
+        self.outcomes.iter().filter(|o| o.label() == label).count()
+    }
+}
+
+impl fmt::Display for PackingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.count("proven-feasible"))
+    }
+}
+
+impl Answer for PackingReport {
+    fn mode(&self) -> Option<String> {
+        match self.count("timed-out") {
+            0 => None,
+            timed_out => Some(format!("{}: {} of {} regions timed out", LOWER_BOUND, timed_out, self.outcomes.len())),
+        }
+    }
+}
+
+fn bbox(orient: &Shape) -> (usize, usize) {
+    let maxx = orient.iter().map(|(x, _)| *x).max().unwrap_or(0);
+    let maxy = orient.iter().map(|(_, y)| *y).max().unwrap_or(0);
+    ((maxx + 1) as usize, (maxy + 1) as usize)
+}
+
+/// Difference between black and white cells a shape covers on a checkerboard.
+/// It is the same for every orientation, up to sign.
+fn imbalance(cells: &Shape) -> usize {
+    let black = cells.iter().filter(|(x, y)| (x + y) % 2 == 0).count();
+    black.abs_diff(cells.len() - black)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// Whether the cells of a shape are joined through shared edges.
+fn is_connected(cells: &Shape) -> bool {
+    let Some(&start) = cells.first() else { return true };
+    let mut seen = vec![start];
+    let mut todo = vec![start];
+    while let Some((x, y)) = todo.pop() {
+        for n in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if cells.contains(&n) && !seen.contains(&n) {
+                seen.push(n);
+                todo.push(n);
+            }
+        }
+    }
+    seen.len() == cells.len()
+}
+
+/// Every placement of every orientation of every shape that has pieces to place.
+fn placements(w: usize, h: usize, counts: &[usize], shape_orients: &[Vec<Shape>]) -> Vec<Placement> {
+    let mut out = Vec::new();
+    for (shape, orients) in shape_orients.iter().enumerate() {
+        if counts[shape] == 0 {
+            continue;
+        }
+        for orient in orients {
+            let (bw, bh) = bbox(orient);
+            if bw > w || bh > h {
+                continue;
+            }
+            for oy in 0..=(h - bh) {
+                for ox in 0..=(w - bw) {
+                    let cells = orient.iter().map(|&(x, y)| (oy + y as usize) * w + ox + x as usize).collect();
+                    out.push(Placement { shape, cells });
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Cheap constructive proof: cut the region into equal slots that every shape fits into
+/// (in some orientation) and give each piece its own slot.
+fn pack_in_slots(w: usize, h: usize, counts: &[usize], shape_orients: &[Vec<Shape>]) -> Option<Layout> {
+    let pieces: usize = counts.iter().sum();
+    let used: Vec<usize> = (0..counts.len()).filter(|&s| counts[s] > 0).collect();
+    let mut best: Option<(usize, usize, usize)> = None;
+    for &s in &used {
+        for (sw, sh) in shape_orients[s].iter().map(bbox) {
+            let fits_all = used.iter().all(|&t| shape_orients[t].iter().any(|o| {
+                let (bw, bh) = bbox(o);
+                bw <= sw && bh <= sh
+            }));
+            let slots = (w / sw) * (h / sh);
+            if fits_all && slots >= pieces && best.is_none_or(|(_, _, b)| slots > b) {
+                best = Some((sw, sh, slots));
+            }
+        }
+    }
+    let (sw, sh, _) = best?;
+    let per_row = w / sw;
+    let mut chosen = Vec::with_capacity(pieces);
+    for &s in &used {
+        let orient = shape_orients[s].iter().find(|o| {
+            let (bw, bh) = bbox(o);
+            bw <= sw && bh <= sh
+        })?;
+        for _ in 0..counts[s] {
+            let slot = chosen.len();
+            let (ox, oy) = ((slot % per_row) * sw, (slot / per_row) * sh);
+            let cells = orient.iter().map(|&(x, y)| (oy + y as usize) * w + ox + x as usize).collect();
+            chosen.push(Placement { shape: s, cells });
+        }
+    }
+    Some(Layout::new(w, h, &chosen.iter().collect::<Vec<_>>()))
+}
+
+const ROOT: usize = 0;
+
+/// Dancing links over a generalised exact cover: one primary column per shape that must be
+/// covered `count` times, and one secondary column per cell that may be covered at most once.
+struct Dlx {
+    l: Vec<usize>,
+    r: Vec<usize>,
+    u: Vec<usize>,
+    d: Vec<usize>,
+    col: Vec<usize>,
+    row: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl Dlx {
+    fn new(primary: usize, secondary: usize) -> Self {
+        let n = primary + secondary + 1;
+        let mut dlx = Dlx {
+            l: vec![0; n],
+            r: vec![0; n],
+            u: (0..n).collect(),
+            d: (0..n).collect(),
+            col: (0..n).collect(),
+            row: vec![usize::MAX; n],
+            size: vec![0; n],
+        };
+        for i in 0..=primary {
+            dlx.r[i] = if i == primary { ROOT } else { i + 1 };
+            dlx.l[i] = if i == ROOT { primary } else { i - 1 };
+        }
+        // secondary headers stay out of the root ring
+        for i in primary + 1..n {
+            dlx.l[i] = i;
+            dlx.r[i] = i;
+        }
+        dlx
+    }
+
+    fn add_row(&mut self, row: usize, cols: &[usize]) {
+        let first = self.l.len();
+        for (k, &c) in cols.iter().enumerate() {
+            let node = first + k;
+            let up = self.u[c];
+            self.u.push(up);
+            self.d.push(c);
+            self.d[up] = node;
+            self.u[c] = node;
+            self.col.push(c);
+            self.row.push(row);
+            self.size[c] += 1;
+            self.l.push(if k == 0 { first + cols.len() - 1 } else { node - 1 });
+            self.r.push(if k + 1 == cols.len() { first } else { node + 1 });
+        }
+    }
+
+    fn unlink(&mut self, j: usize) {
+        let (u, d) = (self.u[j], self.d[j]);
+        self.d[u] = d;
+        self.u[d] = u;
+        self.size[self.col[j]] -= 1;
+    }
+
+    fn relink(&mut self, j: usize) {
+        let (u, d) = (self.u[j], self.d[j]);
+        self.d[u] = j;
+        self.u[d] = j;
+        self.size[self.col[j]] += 1;
+    }
+
+    fn cover(&mut self, c: usize) {
+        let (l, r) = (self.l[c], self.r[c]);
+        self.r[l] = r;
+        self.l[r] = l;
+        let mut i = self.d[c];
+        while i != c {
+            let mut j = self.r[i];
+            while j != i {
+                self.unlink(j);
+                j = self.r[j];
+            }
+            i = self.d[i];
+        }
+    }
+
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.u[c];
+        while i != c {
+            let mut j = self.l[i];
+            while j != i {
+                self.relink(j);
+                j = self.l[j];
+            }
+            i = self.u[i];
+        }
+        let (l, r) = (self.l[c], self.r[c]);
+        self.r[l] = c;
+        self.l[r] = c;
+    }
+}
+
+/// Algorithm X over [`Dlx`], branching on cells rather than shapes: the undecided cell with
+/// the fewest placements left is either covered by one of them or left empty. Those choices
+/// never overlap, and pieces of one shape are only counted, never told apart, so no packing
+/// is reached twice, however many identical pieces it has.
+///
+/// A cell no placement can reach any more is dead: it will end up empty. Dead and empty cells
+/// together may not exceed the region's slack (its area minus the presents'). The cells still
+/// reachable have to leave enough of each checkerboard colour for the pieces, and each pocket
+/// of them wastes whatever its size leaves over after whole pieces.
+struct Packer<'a> {
+    dlx: Dlx,
+    placements: &'a [Placement],
+    need: Vec<usize>,
+    areas: Vec<usize>,
+    imbalances: Vec<usize>,
+    /// Whether every shape is connected through edges, so a piece never spans two pockets.
+    connected: bool,
+    shapes: usize,
+    w: usize,
+    cells: usize,
+    /// Cells already covered or left empty.
+    decided: Bitboard,
+    /// Cells that may still be left empty.
+    slack: usize,
+    chosen: Vec<usize>,
+    deadline: Instant,
+    nodes: u64,
+    timed_out: bool,
+}
+
+impl Packer<'_> {
+    fn column(&self, cell: usize) -> usize {
+        self.shapes + 1 + cell
+    }
+
+    /// The undecided cell with the fewest placements, or `None` if the pieces left can't fit
+    /// into the cells that are still reachable.
+    fn next_cell(&self) -> Option<usize> {
+        let (mut black, mut white, mut best) = (0usize, 0usize, None);
+        for cell in (0..self.cells).filter(|&c| !self.decided.get(c)) {
+            let size = self.dlx.size[self.column(cell)];
+            if size == 0 {
+                continue;
+            }
+            if (cell % self.w + cell / self.w).is_multiple_of(2) { black += 1 } else { white += 1 }
+            if best.is_none_or(|(_, s)| size < s) {
+                best = Some((cell, size));
+            }
+        }
+        let area: usize = (0..self.shapes).map(|s| self.need[s] * self.areas[s]).sum();
+        // each piece covers at least (area - imbalance) / 2 cells of either colour
+        let per_colour: usize = (0..self.shapes).map(|s| self.need[s] * (self.areas[s] - self.imbalances[s]) / 2).sum();
+        if area > black + white || per_colour > black || per_colour > white || area > self.usable_cells() {
+            return None;
+        }
+        // a shape with fewer placements left than pieces to place can't be finished
+        let mut c = self.dlx.r[ROOT];
+        while c != ROOT {
+            if self.dlx.size[c] < self.need[c - 1] {
+                return None;
+            }
+            c = self.dlx.r[c];
+        }
+        best.map(|(cell, _)| cell)
+    }
+
+    /// How many reachable cells pieces can cover at most, given that a piece lies inside one
+    /// pocket of reachable cells and every piece area is a multiple of the same number.
+    fn usable_cells(&self) -> usize {
+        let step = (0..self.shapes).filter(|&s| self.need[s] > 0).fold(0, |g, s| gcd(g, self.areas[s]));
+        if !self.connected || step < 2 {
+            return self.cells;
+        }
+        let live = |c: usize| !self.decided.get(c) && self.dlx.size[self.column(c)] > 0;
+        let mut seen = vec![false; self.cells];
+        let mut usable = 0;
+        for start in 0..self.cells {
+            if seen[start] || !live(start) {
+                continue;
+            }
+            seen[start] = true;
+            let (mut todo, mut size) = (vec![start], 0);
+            while let Some(c) = todo.pop() {
+                size += 1;
+                let (x, y) = (c % self.w, c / self.w);
+                let neighbours = [(x > 0).then(|| c - 1), (x + 1 < self.w).then(|| c + 1), (y > 0).then(|| c - self.w), Some(c + self.w)];
+                for n in neighbours.into_iter().flatten().filter(|&n| n < self.cells) {
+                    if !seen[n] && live(n) {
+                        seen[n] = true;
+                        todo.push(n);
+                    }
+                }
+            }
+            usable += size - size % step;
+        }
+        usable
+    }
+
+    /// Edges of `cells` that touch the region's border or a cell already decided.
+    fn contact(&self, cells: &[usize]) -> usize {
+        let (w, h) = (self.w, self.cells / self.w);
+        let mut touching = 0;
+        for &c in cells {
+            let (x, y) = (c % w, c / w);
+            let neighbours = [(x > 0).then(|| c - 1), (x + 1 < w).then(|| c + 1), (y > 0).then(|| c - w), (y + 1 < h).then(|| c + w)];
+            touching += neighbours.iter().filter(|n| n.is_none_or(|n| self.decided.get(n) || self.dlx.size[self.column(n)] == 0)).count();
+        }
+        touching
+    }
+
+    /// Puts the placement of row `node` into the region.
+    fn select(&mut self, node: usize) {
+        let shape = self.placements[self.dlx.row[node]].shape;
+        self.need[shape] -= 1;
+        let mut j = node;
+        loop {
+            let col = self.dlx.col[j];
+            if col > self.shapes {
+                self.dlx.cover(col);
+                self.decided.set(col - self.shapes - 1);
+            } else if self.need[shape] == 0 {
+                self.dlx.cover(col);
+            }
+            j = self.dlx.r[j];
+            if j == node {
+                break;
+            }
+        }
+        self.chosen.push(self.dlx.row[node]);
+    }
+
+    /// Undoes [`Packer::select`] of the same row.
+    fn deselect(&mut self, node: usize) {
+        self.chosen.pop();
+        let shape = self.placements[self.dlx.row[node]].shape;
+        let mut j = node;
+        loop {
+            j = self.dlx.l[j];
+            let col = self.dlx.col[j];
+            if col > self.shapes {
+                self.decided.clear(col - self.shapes - 1);
+                self.dlx.uncover(col);
+            } else if self.need[shape] == 0 {
+                self.dlx.uncover(col);
+            }
+            if j == node {
+                break;
+            }
+        }
+        self.need[shape] += 1;
+    }
+
+    fn search(&mut self) -> bool {
+        self.nodes += 1;
+        if self.nodes.is_multiple_of(1024) && Instant::now() > self.deadline {
+            self.timed_out = true;
+        }
+        if self.timed_out {
+            return false;
+        }
+        if self.dlx.r[ROOT] == ROOT {
+            return true;
+        }
+        let Some(cell) = self.next_cell() else { return false };
+        let col = self.column(cell);
+
+        let mut options = Vec::with_capacity(self.dlx.size[col]);
+        let mut node = self.dlx.d[col];
+        while node != col {
+            options.push((self.contact(&self.placements[self.dlx.row[node]].cells), node));
+            node = self.dlx.d[node];
+        }
+        // snug placements first: they leave the fewest awkward gaps behind
+        options.sort_by_key(|&(contact, _)| std::cmp::Reverse(contact));
+        for (_, node) in options {
+            self.select(node);
+            if self.search() {
+                return true;
+            }
+            self.deselect(node);
+            if self.timed_out {
+                return false;
+            }
+        }
+
+        // or nothing covers the cell, if the region has room to spare
+        if self.slack == 0 {
+            return false;
+        }
+        self.slack -= 1;
+        self.dlx.cover(col);
+        self.decided.set(cell);
+        if self.search() {
+            return true;
+        }
+        self.decided.clear(cell);
+        self.dlx.uncover(col);
+        self.slack += 1;
+        false
+    }
+}
+
+/// Decides whether the presents in `counts` fit into a `w` x `h` region.
+pub fn pack_region(w: usize, h: usize, counts: &[usize], shape_orients: &[Vec<Shape>], timeout: Duration) -> PackOutcome {
+    let areas: Vec<usize> = shape_orients.iter().map(|o| o.first().map_or(0, |c| c.len())).collect();
+    let imbalances: Vec<usize> = shape_orients.iter().map(|o| o.first().map_or(0, imbalance)).collect();
+    let needed: usize = counts.iter().zip(&areas).map(|(c, a)| c * a).sum();
+    if needed > w * h {
+        return PackOutcome::Infeasible(format!("presents need {} cells but the region has {}", needed, w * h));
+    }
+    let per_colour: usize = (0..counts.len()).map(|s| counts[s] * (areas[s] - imbalances[s]) / 2).sum();
+    if per_colour > (w * h) / 2 {
+        return PackOutcome::Infeasible(format!(
+            "presents need at least {} cells of each checkerboard colour but the region has {} of the rarer one",
+            per_colour,
+            (w * h) / 2
+        ));
+    }
+    if let Some(layout) = pack_in_slots(w, h, counts, shape_orients) {
+        return PackOutcome::Feasible(layout);
+    }
+
+    let placements = placements(w, h, counts, shape_orients);
+    let shapes = shape_orients.len();
+    if let Some(s) = (0..shapes).find(|&s| counts[s] > 0 && !placements.iter().any(|p| p.shape == s)) {
+        return PackOutcome::Infeasible(format!("shape {} doesn't fit into the region in any orientation", s));
+    }
+    let mut dlx = Dlx::new(shapes, w * h);
+    for (i, p) in placements.iter().enumerate() {
+        let mut cols = vec![p.shape + 1];
+        cols.extend(p.cells.iter().map(|&c| shapes + 1 + c));
+        dlx.add_row(i, &cols);
+    }
+    // shapes without pieces are already satisfied
+    for s in (0..shapes).rev() {
+        if counts[s] == 0 {
+            dlx.cover(s + 1);
+        }
+    }
+
+    let mut packer = Packer {
+        dlx,
+        placements: &placements,
+        need: counts.to_vec(),
+        areas,
+        imbalances,
+        connected: shape_orients.iter().all(|o| o.first().is_none_or(is_connected)),
+        shapes,
+        w,
+        cells: w * h,
+        decided: Bitboard::new(w * h),
+        slack: w * h - needed,
+        chosen: Vec::new(),
+        deadline: Instant::now() + timeout,
+        nodes: 0,
+        timed_out: false,
+    };
+    if packer.search() {
+        let chosen: Vec<&Placement> = packer.chosen.iter().map(|&i| &packer.placements[i]).collect();
+        PackOutcome::Feasible(Layout::new(w, h, &chosen))
+    } else if packer.timed_out {
+        PackOutcome::TimedOut
+    } else {
+        PackOutcome::Infeasible(format!("exhaustive search ({} nodes) found no packing", packer.nodes))
+    }
+}
+
+/// What `--verbose` prints about a region: its outcome, then the layout or the reason.
+fn describe(region: usize, w: usize, h: usize, outcome: &PackOutcome) -> String {
+    let head = format!("region {} ({}x{}): {}", region, w, h, outcome.label());
+    match outcome {
+        PackOutcome::Feasible(layout) => format!("{}\n{}", head, layout),
+        PackOutcome::Infeasible(reason) => format!("{}: {}\n", head, reason),
+        PackOutcome::TimedOut => format!("{}\n", head),
+    }
+}
+
+/// All distinct orientations of each present shape, plus the regions to fill.
+pub struct Presents {
+    shape_orients: Vec<Vec<Shape>>,
+    regions: Vec<Region>,
+    packing: Packing,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const HAS_PART2: bool = false;
+
+    type Input = Presents;
+    type Answer1 = PackingReport;
+    type Answer2 = usize;
+    type Params = Packing;
+
+    fn parse(input: &str) -> Result<Presents, ParseError> {
+        Self::parse_with(input, &Packing::default())
+    }
+
+    fn parse_with(input: &str, packing: &Packing) -> Result<Presents, ParseError> {
+        let (shapes, mut regions) = parse_input(input)?;
+        let shapes_cells: Vec<Shape> = shapes.iter().map(shape_cells).collect();
+        let shape_orients: Vec<Vec<Shape>> = shapes_cells.iter().map(transforms).collect();
+        for (_, _, counts) in regions.iter_mut() {
+            if counts.len() < shapes_cells.len() {
+                counts.resize(shapes_cells.len(), 0);
+            }
+        }
+        Ok(Presents { shape_orients, regions, packing: *packing })
+    }
+
+    fn part1(input: &Presents) -> Result<PackingReport> {
+        let mut outcomes = Vec::with_capacity(input.regions.len());
+        for (i, (w, h, counts)) in input.regions.iter().enumerate() {
+            let outcome = pack_region(*w, *h, counts, &input.shape_orients, input.packing.timeout());
+            if cli::verbosity() == Verbosity::Verbose {
+                eprint!("{}", describe(i + 1, *w, *h, &outcome));
+            }
+            outcomes.push(outcome);
+        }
+        Ok(PackingReport { outcomes })
+    }
+
+    fn part2(_: &Presents) -> Result<usize> {
+        bail!("Day 12 has no part 2")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day12>().unwrap();
+    }
+
+    fn example() -> Presents {
+        Day12::parse(&std::fs::read_to_string(fixtures::example_path(12, 1)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn example_regions() {
+        let input = example();
+        let report = Day12::part1(&input).unwrap();
+        let labels: Vec<&str> = report.outcomes.iter().map(|o| o.label()).collect();
+        assert_eq!(vec!["proven-feasible", "proven-feasible", "proven-infeasible"], labels);
+        assert_eq!("2", report.to_string());
+    }
+
+    #[test]
+    fn verbose_layouts() {
+        let input = example();
+        let described: Vec<String> = input
+            .regions
+            .iter()
+            .enumerate()
+            .map(|(i, (w, h, counts))| describe(i + 1, *w, *h, &pack_region(*w, *h, counts, &input.shape_orients, input.packing.timeout())))
+            .collect();
+        assert_eq!("region 1 (4x4): proven-feasible\nAAA.\nABBB\nAAAB\n.BBB\n", described[0]);
+        let layout = "AAA.CCC.E.E.\nAA..CDDDEEE.\nAABBCCCDEFEF\n.BBB.DDD.FFF\n.BB......F.F\n";
+        assert_eq!(format!("region 2 (12x5): proven-feasible\n{}", layout), described[1]);
+        assert!(described[2].starts_with("region 3 (12x5): proven-infeasible: "), "{}", described[2]);
+    }
+
+    #[test]
+    fn layouts_cover_each_piece_once() {
+        let input = example();
+        let (w, h, counts) = &input.regions[1];
+        let PackOutcome::Feasible(layout) = pack_region(*w, *h, counts, &input.shape_orients, input.packing.timeout()) else {
+            panic!("expected a layout");
+        };
+        let filled = layout.cells.points().filter(|&p| layout.cells[p].is_some()).count();
+        assert_eq!(7 * counts.iter().sum::<usize>(), filled);
+    }
+
+    #[test]
+    fn tight_regions() {
+        // 23 presents fill 161 of the 176 cells
+        let input = "0:\n###\n#..\n###\n\n1:\n.##\n.##\n###\n\n2:\n###\n###\n..#\n\n3:\n##.\n###\n##.\n\n\
+4:\n###\n.##\n##.\n\n5:\n##.\n###\n#.#\n\n16x11: 3 5 7 4 4 0\n";
+        let input = Day12::parse_with(input, &Packing { timeout_ms: 20_000 }).unwrap();
+        let (w, h, counts) = &input.regions[0];
+        let PackOutcome::Feasible(layout) = pack_region(*w, *h, counts, &input.shape_orients, input.packing.timeout()) else {
+            panic!("expected a layout");
+        };
+        let mut sizes = vec![0; 23];
+        for p in layout.cells.points() {
+            if let Some(piece) = layout.cells[p] {
+                sizes[piece] += 1;
+            }
+        }
+        assert!(sizes.iter().all(|&s| s == 7), "{:?}", sizes);
+    }
+
+    #[test]
+    fn timeouts_make_the_count_a_lower_bound() {
+        let report = PackingReport { outcomes: vec![PackOutcome::Infeasible("too big".to_string()), PackOutcome::TimedOut] };
+        assert_eq!("0", report.to_string());
+        assert_eq!(Some("lower bound: 1 of 2 regions timed out".to_string()), report.mode());
+        assert_eq!(None, PackingReport { outcomes: Vec::new() }.mode());
+    }
+}
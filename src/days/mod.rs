@@ -0,0 +1,33 @@
+use crate::runner::{solve, Runner};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+
+pub const ALL: &[(u8, Runner)] = &[
+    (1, solve::<day01::Day01>),
+    (2, solve::<day02::Day02>),
+    (3, solve::<day03::Day03>),
+    (4, solve::<day04::Day04>),
+    (5, solve::<day05::Day05>),
+    (6, solve::<day06::Day06>),
+    (7, solve::<day07::Day07>),
+    (8, solve::<day08::Day08>),
+    (9, solve::<day09::Day09>),
+    (10, solve::<day10::Day10>),
+    (11, solve::<day11::Day11>),
+    (12, solve::<day12::Day12>),
+];
+
+pub fn runner(day: u8) -> Option<Runner> {
+    ALL.iter().find(|(d, _)| *d == day).map(|(_, r)| *r)
+}
@@ -0,0 +1,60 @@
+use crate::answers::{self, Answers, Status};
+use crate::params;
+use crate::runner::{self, DayResult, RunOptions};
+use crate::Solution;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The puzzle examples: `examples/DD.txt`, further ones as `examples/DD-2.txt` and so on,
+/// with their expected answers in `examples/answers.toml` under the same names.
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+/// Path of a day's `n`th example; the first one has no number.
+pub fn example_path(day: u8, n: usize) -> PathBuf {
+    let variant = (n > 1).then(|| n.to_string());
+    examples_dir().join(format!("{}.txt", answers::key(day, variant.as_deref())))
+}
+
+/// Every example of a day, in order, with its key in `answers.toml`.
+pub fn examples(day: u8) -> Vec<(String, PathBuf)> {
+    (1..)
+        .map(|n| (n, example_path(day, n)))
+        .take_while(|(_, p)| p.is_file())
+        .map(|(n, p)| (answers::key(day, (n > 1).then(|| n.to_string()).as_deref()), p))
+        .collect()
+}
+
+/// Runs a day on each of its examples and compares every answer that has an expected value.
+/// Fails on any mismatch, or if no answer was checked at all.
+pub fn check_examples<S: Solution>() -> Result<Vec<DayResult>> {
+    let expected = Answers::load(&examples_dir().join(answers::ANSWERS_FILE))?;
+    let mut results = Vec::new();
+    let mut checked = 0;
+    let mut mismatches = Vec::new();
+    for (key, path) in examples(S::DAY) {
+        let text = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let options = RunOptions { params: params::example(&key)?, ..RunOptions::default() };
+        let res = runner::solve::<S>(&text, &options).with_context(|| format!("example {}", key))?;
+        for check in expected.check(&key, &res) {
+            match check.status {
+                Status::Pass => checked += 1,
+                Status::Fail(want) => {
+                    checked += 1;
+                    mismatches.push(format!("example {} part {}: got {}, expected {}", key, check.part, check.actual, want));
+                }
+                Status::Missing => {}
+            }
+        }
+        results.push(res);
+    }
+    if !mismatches.is_empty() {
+        bail!("{}", mismatches.join("\n"));
+    }
+    if checked == 0 {
+        bail!("no expected example answers for day {:02} in {}", S::DAY, examples_dir().display());
+    }
+    Ok(results)
+}
@@ -0,0 +1,633 @@
+//! Seeded generators of synthetic puzzle inputs, one per day, for inputs larger or nastier
+//! than the real ones. The same day, seed, size and edge cases always give the same text.
+
+use anyhow::{bail, Result};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// SplitMix64. Small and fully specified, so generated inputs never change with a
+/// dependency upgrade or platform.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+        (lo as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0, n as i64 - 1) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// What to generate; see [`GENERATORS`] for what size and the edge cases mean per day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GenOptions {
+    pub seed: u64,
+    /// `None` uses the day's default, which is about the size of a real input.
+    pub size: Option<usize>,
+    pub edges: Vec<String>,
+}
+
+/// State handed to a day's generator.
+pub struct Ctx {
+    pub rng: Rng,
+    pub size: usize,
+    edges: Vec<String>,
+}
+
+impl Ctx {
+    /// Whether the edge case `name` was asked for.
+    pub fn edge(&self, name: &str) -> bool {
+        self.edges.iter().any(|e| e == name)
+    }
+}
+
+pub struct Generator {
+    pub day: u8,
+    pub default_size: usize,
+    /// What the size counts.
+    pub size: &'static str,
+    /// `(name, description)` of each edge case.
+    pub edges: &'static [(&'static str, &'static str)],
+    pub generate: fn(&mut Ctx) -> String,
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        default_size: 4000,
+        size: "rotations",
+        edges: &[("huge", "distances up to a billion clicks"), ("zero", "rotations that often stop exactly on 0")],
+        generate: day01,
+    },
+    Generator {
+        day: 2,
+        default_size: 40,
+        size: "ranges",
+        edges: &[("digits", "ranges that cross a power of ten"), ("single", "ranges of a single ID")],
+        generate: day02,
+    },
+    Generator {
+        day: 3,
+        default_size: 200,
+        size: "banks",
+        edges: &[("flat", "banks of one repeated digit"), ("short", "banks of exactly 12 batteries")],
+        generate: day03,
+    },
+    Generator {
+        day: 4,
+        default_size: 140,
+        size: "grid side",
+        edges: &[("full", "every cell holds a roll"), ("empty", "no rolls at all")],
+        generate: day04,
+    },
+    Generator {
+        day: 5,
+        default_size: 180,
+        size: "fresh ranges (with four times as many IDs)",
+        edges: &[("overlap", "nested, adjacent and repeated ranges"), ("extreme", "ranges touching 0 and i64::MAX")],
+        generate: day05,
+    },
+    Generator {
+        day: 6,
+        default_size: 1000,
+        size: "problems",
+        edges: &[("tall", "eight numbers per problem"), ("single", "one number per problem")],
+        generate: day06,
+    },
+    Generator {
+        day: 7,
+        default_size: 141,
+        size: "manifold width",
+        edges: &[("full", "a splitter everywhere a beam can reach"), ("border", "splitters that send beams off the sides")],
+        generate: day07,
+    },
+    Generator {
+        day: 8,
+        default_size: 1000,
+        size: "junction boxes",
+        edges: &[
+            ("duplicates", "boxes at the same position"),
+            ("lattice", "boxes on a coarse lattice, so many distances tie"),
+            ("line", "every box on one line"),
+        ],
+        generate: day08,
+    },
+    Generator {
+        day: 9,
+        default_size: 500,
+        size: "red tiles (polygon vertices)",
+        edges: &[("adjacent", "vertices in neighbouring rows and columns"), ("small", "coordinates below 60")],
+        generate: day09,
+    },
+    Generator {
+        day: 10,
+        default_size: 180,
+        size: "machines",
+        edges: &[("wide", "up to 16 lights and 20 buttons"), ("zero", "machines that start in their target state")],
+        generate: day10,
+    },
+    Generator {
+        day: 11,
+        default_size: 600,
+        size: "devices",
+        edges: &[
+            ("deep", "a narrow DAG whose path counts do not fit in 128 bits"),
+            ("cycle", "a loop on the way from svr to out"),
+            ("unreachable", "dac cannot be reached from svr"),
+        ],
+        generate: day11,
+    },
+    Generator {
+        day: 12,
+        default_size: 1000,
+        size: "regions",
+        edges: &[("tight", "regions whose area barely fits the presents"), ("tiny", "regions at most 8 wide")],
+        generate: day12,
+    },
+];
+
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+/// Generates an input for `day`.
+pub fn generate(day: u8, opts: &GenOptions) -> Result<String> {
+    let Some(g) = generator(day) else { bail!("no generator for day {}", day) };
+    for edge in &opts.edges {
+        if !g.edges.iter().any(|&(name, _)| name == edge) {
+            let known: Vec<&str> = g.edges.iter().map(|&(name, _)| name).collect();
+            bail!("day {:02} has no edge case '{}'; it knows {}", day, edge, known.join(", "));
+        }
+    }
+    let size = opts.size.unwrap_or(g.default_size);
+    if size == 0 {
+        bail!("size must be at least 1");
+    }
+    let mut ctx = Ctx { rng: Rng::new(opts.seed), size, edges: opts.edges.clone() };
+    Ok((g.generate)(&mut ctx))
+}
+
+fn day01(ctx: &mut Ctx) -> String {
+    let mut out = String::new();
+    let mut pos = 50;
+    for _ in 0..ctx.size {
+        let right = ctx.rng.chance(0.5);
+        let mut dist = ctx.rng.range(1, 999);
+        if ctx.edge("huge") && ctx.rng.chance(0.3) {
+            dist = ctx.rng.range(1, 1_000_000_000);
+        }
+        if ctx.edge("zero") && ctx.rng.chance(0.4) {
+            // land on 0, possibly after some full turns
+            let to_zero = if right { (100 - pos) % 100 } else { pos };
+            dist = to_zero + 100 * ctx.rng.range(if to_zero == 0 { 1 } else { 0 }, 3);
+        }
+        pos = if right { (pos + dist) % 100 } else { (pos - dist).rem_euclid(100) };
+        let _ = writeln!(out, "{}{}", if right { 'R' } else { 'L' }, dist);
+    }
+    out
+}
+
+fn day02(ctx: &mut Ctx) -> String {
+    let ranges: Vec<String> = (0..ctx.size)
+        .map(|_| {
+            let digits = ctx.rng.range(1, 10) as u32;
+            let (lo, hi) = if ctx.edge("digits") && ctx.rng.chance(0.5) {
+                let power = 10i64.pow(digits);
+                (power - ctx.rng.range(1, (power - 1).min(5000)), power + ctx.rng.range(0, 5000))
+            } else {
+                let lo = ctx.rng.range(10i64.pow(digits - 1), 10i64.pow(digits) - 1);
+                let len = if ctx.edge("single") && ctx.rng.chance(0.5) { 0 } else { ctx.rng.range(0, 10i64.pow(digits / 2 + 1)) };
+                (lo, lo + len)
+            };
+            format!("{}-{}", lo, hi)
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+fn day03(ctx: &mut Ctx) -> String {
+    let width = if ctx.edge("short") { 12 } else { 100 };
+    let mut out = String::new();
+    for _ in 0..ctx.size {
+        let flat = ctx.edge("flat") && ctx.rng.chance(0.5);
+        let d = ctx.rng.range(1, 9);
+        let bank: String = (0..width)
+            .map(|_| char::from_digit((if flat { d } else { ctx.rng.range(1, 9) }) as u32, 10).unwrap())
+            .collect();
+        out.push_str(&bank);
+        out.push('\n');
+    }
+    out
+}
+
+fn day04(ctx: &mut Ctx) -> String {
+    let density = if ctx.edge("full") {
+        1.0
+    } else if ctx.edge("empty") {
+        0.0
+    } else {
+        0.65
+    };
+    let mut out = String::new();
+    for _ in 0..ctx.size {
+        out.extend((0..ctx.size).map(|_| if ctx.rng.chance(density) { '@' } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
+
+fn day05(ctx: &mut Ctx) -> String {
+    const MAX: i64 = 500_000_000_000_000;
+    let mut ranges: Vec<(i64, i64)> = Vec::new();
+    for _ in 0..ctx.size {
+        let lo = ctx.rng.range(1, MAX);
+        let mut range = (lo, lo + ctx.rng.range(0, 1_000_000_000_000));
+        if ctx.edge("overlap") && !ranges.is_empty() && ctx.rng.chance(0.5) {
+            let &(a, b) = ctx.rng.pick(&ranges);
+            range = match ctx.rng.below(3) {
+                0 => (a, b),
+                1 => (b.saturating_add(1), b.saturating_add(1 + ctx.rng.range(0, 1000))),
+                _ => (ctx.rng.range(a, b), ctx.rng.range(a, b)),
+            };
+            range = (range.0.min(range.1), range.0.max(range.1));
+        }
+        if ctx.edge("extreme") && ctx.rng.chance(0.1) {
+            range = if ctx.rng.chance(0.5) { (i64::MAX - ctx.rng.range(0, 1000), i64::MAX) } else { (0, ctx.rng.range(0, 1000)) };
+        }
+        ranges.push(range);
+    }
+    let mut out = String::new();
+    for &(a, b) in &ranges {
+        let _ = writeln!(out, "{}-{}", a, b);
+    }
+    out.push('\n');
+    for _ in 0..4 * ctx.size {
+        let id = if !ranges.is_empty() && ctx.rng.chance(0.3) {
+            let &(a, b) = ctx.rng.pick(&ranges);
+            ctx.rng.range(a, b)
+        } else {
+            ctx.rng.range(0, MAX)
+        };
+        let _ = writeln!(out, "{}", id);
+    }
+    out
+}
+
+fn day06(ctx: &mut Ctx) -> String {
+    let rows = if ctx.edge("tall") {
+        8
+    } else if ctx.edge("single") {
+        1
+    } else {
+        4
+    };
+    let mut lines = vec![String::new(); rows + 1];
+    for p in 0..ctx.size {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = ctx.rng.range(1, 4) as u32;
+                ctx.rng.range(1, 10i64.pow(digits) - 1).to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(|n| n.len()).max().unwrap_or(1);
+        let left = ctx.rng.chance(0.5);
+        for (line, n) in lines.iter_mut().zip(&numbers) {
+            if p > 0 {
+                line.push(' ');
+            }
+            let _ = if left { write!(line, "{:<w$}", n, w = width) } else { write!(line, "{:>w$}", n, w = width) };
+        }
+        let ops = &mut lines[rows];
+        if p > 0 {
+            ops.push(' ');
+        }
+        let _ = write!(ops, "{:<w$}", if ctx.rng.chance(0.5) { '+' } else { '*' }, w = width);
+    }
+    lines.join("\n") + "\n"
+}
+
+fn day07(ctx: &mut Ctx) -> String {
+    let width = ctx.size.max(3) | 1;
+    let mid = width / 2;
+    let mut grid = vec![vec!['.'; width]; width + 1];
+    grid[0][mid] = 'S';
+    for r in (2..=width).step_by(2) {
+        // positions a beam can reach on this row form a triangle below S
+        let k = r / 2;
+        for j in 0..k {
+            let Some(c) = (mid + 2 * j + 1).checked_sub(k) else { continue };
+            if c < width && (ctx.edge("full") || ctx.rng.chance(0.6)) {
+                grid[r][c] = '^';
+            }
+        }
+        if ctx.edge("border") && ctx.rng.chance(0.3) {
+            let c = if ctx.rng.chance(0.5) { 0 } else { width - 1 };
+            grid[r][c] = '^';
+        }
+    }
+    grid.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+fn day08(ctx: &mut Ctx) -> String {
+    let mut points: Vec<[i64; 3]> = Vec::with_capacity(ctx.size);
+    let dir = [ctx.rng.range(1, 9), ctx.rng.range(1, 9), ctx.rng.range(1, 9)];
+    for _ in 0..ctx.size {
+        let p = if ctx.edge("duplicates") && !points.is_empty() && ctx.rng.chance(0.2) {
+            *ctx.rng.pick(&points)
+        } else if ctx.edge("line") {
+            let t = ctx.rng.range(0, 10_000);
+            [dir[0] * t, dir[1] * t, dir[2] * t]
+        } else if ctx.edge("lattice") {
+            [ctx.rng.range(0, 20) * 1000, ctx.rng.range(0, 20) * 1000, ctx.rng.range(0, 20) * 1000]
+        } else {
+            [ctx.rng.range(0, 99_999), ctx.rng.range(0, 99_999), ctx.rng.range(0, 99_999)]
+        };
+        points.push(p);
+    }
+    points.iter().map(|p| format!("{},{},{}\n", p[0], p[1], p[2])).collect()
+}
+
+/// A rectilinear polygon between a random upper and lower staircase, walked clockwise.
+fn day09(ctx: &mut Ctx) -> String {
+    let max = if ctx.edge("small") { 60 } else { 100_000 };
+    let columns = (ctx.size / 4).clamp(1, max as usize / 4);
+    let mut xs: HashSet<i64> = HashSet::new();
+    while xs.len() < columns + 1 {
+        let x = ctx.rng.range(1, max - 1);
+        xs.insert(x);
+        if ctx.edge("adjacent") && ctx.rng.chance(0.3) && x + 1 < max {
+            xs.insert(x + 1);
+        }
+    }
+    let mut xs: Vec<i64> = xs.into_iter().collect();
+    xs.sort_unstable();
+    xs.truncate(columns + 1);
+    // heights must differ between neighbouring columns, or the shared vertex is not a corner
+    let adjacent = ctx.edge("adjacent");
+    let staircase = |lo: i64, hi: i64, rng: &mut Rng| {
+        let mut ys: Vec<i64> = Vec::with_capacity(columns);
+        while ys.len() < columns {
+            let y = match ys.last() {
+                Some(&last) if adjacent && rng.chance(0.3) => (last + if rng.chance(0.5) { 1 } else { -1 }).clamp(lo, hi),
+                _ => rng.range(lo, hi),
+            };
+            if ys.last() != Some(&y) {
+                ys.push(y);
+            }
+        }
+        ys
+    };
+    let half = max / 2;
+    let top = staircase(half + 1, max, &mut ctx.rng);
+    let bottom = staircase(0, half - 1, &mut ctx.rng);
+    let mut vertices = Vec::new();
+    for i in 0..columns {
+        vertices.push((xs[i], top[i]));
+        vertices.push((xs[i + 1], top[i]));
+    }
+    for i in (0..columns).rev() {
+        vertices.push((xs[i + 1], bottom[i]));
+        vertices.push((xs[i], bottom[i]));
+    }
+    vertices.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+}
+
+fn day10(ctx: &mut Ctx) -> String {
+    let (max_lights, max_buttons) = if ctx.edge("wide") { (16, 20) } else { (10, 13) };
+    let mut out = String::new();
+    for _ in 0..ctx.size {
+        let lights = ctx.rng.range(3, max_lights) as usize;
+        // distinct buttons that between them reach every light, as in the real machines
+        let count = (ctx.rng.range(3, max_buttons) as usize).min((1 << lights) - 1);
+        let mut buttons: Vec<Vec<usize>> = Vec::with_capacity(count);
+        while buttons.len() < count {
+            let mut wires: Vec<usize> = (0..lights).filter(|_| ctx.rng.chance(0.4)).collect();
+            if buttons.len() + 1 == count {
+                wires.extend((0..lights).filter(|l| !buttons.iter().flatten().any(|w| w == l)));
+                wires.sort_unstable();
+                wires.dedup();
+            }
+            if !wires.is_empty() && !buttons.contains(&wires) {
+                buttons.push(wires);
+            }
+        }
+        // targets come from actual presses, so every machine can be solved
+        let zero = ctx.edge("zero") && ctx.rng.chance(0.3);
+        let presses: Vec<i64> = buttons.iter().map(|_| if zero { 0 } else { ctx.rng.range(0, 30) }).collect();
+        let toggles: Vec<bool> = buttons.iter().map(|_| !zero && ctx.rng.chance(0.5)).collect();
+        let mut lit = vec![false; lights];
+        let mut joltage = vec![0; lights];
+        for (b, wires) in buttons.iter().enumerate() {
+            for &w in wires {
+                lit[w] ^= toggles[b];
+                joltage[w] += presses[b];
+            }
+        }
+        let pattern: String = lit.iter().map(|&on| if on { '#' } else { '.' }).collect();
+        let wiring: Vec<String> =
+            buttons.iter().map(|w| format!("({})", w.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(","))).collect();
+        let joltage: Vec<String> = joltage.iter().map(|j| j.to_string()).collect();
+        let _ = writeln!(out, "[{}] {} {{{}}}", pattern, wiring.join(" "), joltage.join(","));
+    }
+    out
+}
+
+/// Distinct three-letter device names, skipping the ones the puzzle gives a meaning.
+fn device_names(rng: &mut Rng, n: usize) -> Vec<String> {
+    let reserved = ["svr", "you", "dac", "fft", "out"];
+    let mut seen: HashSet<String> = reserved.iter().map(|s| s.to_string()).collect();
+    let mut names = Vec::with_capacity(n);
+    while names.len() < n {
+        let name: String = (0..3).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// A random DAG in topological order: `svr` first, `out` last, `you`, `fft` and `dac` spread
+/// in between, every device reachable from `svr` and every device leading to `out`.
+fn day11(ctx: &mut Ctx) -> String {
+    let n = ctx.size.max(6);
+    let mut names = device_names(&mut ctx.rng, n);
+    names[0] = "svr".to_string();
+    names[n / 5] = "you".to_string();
+    names[n / 3] = "fft".to_string();
+    names[2 * n / 3] = "dac".to_string();
+    names[n - 1] = "out".to_string();
+    let (window, fan_out) = if ctx.edge("deep") { (2, 2) } else { (12, 3) };
+
+    let mut edges: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut has_input = vec![false; n];
+    for (i, out) in edges.iter_mut().enumerate().take(n - 1) {
+        let reach = (n - 1 - i).min(window) as i64;
+        let count = if ctx.edge("deep") { fan_out } else { ctx.rng.range(1, fan_out) }.min(reach);
+        while (out.len() as i64) < count {
+            let j = i + ctx.rng.range(1, reach) as usize;
+            if !out.contains(&j) {
+                out.push(j);
+                has_input[j] = true;
+            }
+        }
+    }
+    for (j, _) in has_input.iter().enumerate().skip(1).filter(|&(_, &fed)| !fed) {
+        let i = j - 1 - ctx.rng.below(j.min(window));
+        edges[i].push(j);
+    }
+    let dac = 2 * n / 3;
+    if ctx.edge("unreachable") {
+        for e in edges.iter_mut() {
+            e.retain(|&j| j != dac);
+        }
+    }
+    if ctx.edge("cycle") {
+        // every device is reachable from svr and leads to out, so any back edge will do
+        edges[n / 2].push(n / 4);
+    }
+
+    let mut lines: Vec<String> = (0..n - 1)
+        .filter(|&i| !edges[i].is_empty())
+        .map(|i| format!("{}: {}", names[i], edges[i].iter().map(|&j| names[j].as_str()).collect::<Vec<_>>().join(" ")))
+        .collect();
+    ctx.rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+/// Six random presents, each 7 cells of a 3x3 box and connected, then the regions.
+fn day12(ctx: &mut Ctx) -> String {
+    let mut shapes: Vec<[bool; 9]> = Vec::new();
+    while shapes.len() < 6 {
+        let (a, b) = (ctx.rng.below(9), ctx.rng.below(9));
+        if a == b {
+            continue;
+        }
+        let mut cells = [true; 9];
+        cells[a] = false;
+        cells[b] = false;
+        if connected(&cells) && !shapes.contains(&cells) {
+            shapes.push(cells);
+        }
+    }
+    let mut out = String::new();
+    for (i, cells) in shapes.iter().enumerate() {
+        let _ = writeln!(out, "{}:", i);
+        for row in cells.chunks(3) {
+            out.extend(row.iter().map(|&c| if c { '#' } else { '.' }));
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+    let (lo, hi) = if ctx.edge("tiny") { (3, 8) } else { (35, 50) };
+    for _ in 0..ctx.size {
+        let (w, h) = (ctx.rng.range(lo, hi), ctx.rng.range(lo, hi));
+        // fill to a share of the area: well under it fits easily, over it cannot fit
+        let fill = if ctx.edge("tight") {
+            ctx.rng.range(95, 101) as f64 / 100.0
+        } else if ctx.rng.chance(0.5) {
+            0.6
+        } else {
+            1.1
+        };
+        let presents = ((w * h) as f64 * fill / 7.0) as usize;
+        let mut counts = [0usize; 6];
+        for _ in 0..presents {
+            counts[ctx.rng.below(6)] += 1;
+        }
+        let counts: Vec<String> = counts.iter().map(|c| c.to_string()).collect();
+        let _ = writeln!(out, "{}x{}: {}", w, h, counts.join(" "));
+    }
+    out
+}
+
+fn connected(cells: &[bool; 9]) -> bool {
+    let start = cells.iter().position(|&c| c).unwrap();
+    let mut seen = [false; 9];
+    let mut todo = vec![start];
+    seen[start] = true;
+    while let Some(i) = todo.pop() {
+        let (r, c) = (i / 3, i % 3);
+        let neighbours = [(r > 0).then(|| i - 3), (r < 2).then(|| i + 3), (c > 0).then(|| i - 1), (c < 2).then(|| i + 1)];
+        for j in neighbours.into_iter().flatten() {
+            if cells[j] && !seen[j] {
+                seen[j] = true;
+                todo.push(j);
+            }
+        }
+    }
+    seen.iter().filter(|&&s| s).count() == cells.iter().filter(|&&c| c).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::runner::RunOptions;
+
+    fn opts(seed: u64, size: usize, edges: &[&str]) -> GenOptions {
+        GenOptions { seed, size: Some(size), edges: edges.iter().map(|e| e.to_string()).collect() }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for g in GENERATORS {
+            let a = generate(g.day, &opts(7, 20, &[])).unwrap();
+            assert_eq!(a, generate(g.day, &opts(7, 20, &[])).unwrap(), "day {}", g.day);
+            assert_ne!(a, generate(g.day, &opts(8, 20, &[])).unwrap(), "day {}", g.day);
+        }
+        assert!(generate(1, &opts(0, 5, &["nope"])).unwrap_err().to_string().contains("huge, zero"));
+    }
+
+    #[test]
+    fn every_day_solves_what_it_generates() {
+        for g in GENERATORS {
+            let runner = days::runner(g.day).unwrap();
+            let mut cases: Vec<Vec<&str>> = vec![vec![]];
+            cases.extend(g.edges.iter().map(|&(name, _)| vec![name]));
+            for edges in cases {
+                // a small size keeps day 12's packing search quick in debug builds
+                let size = if g.day == 12 { 2 } else { 12 };
+                let input = generate(g.day, &opts(1, size, &edges)).unwrap();
+                let res = runner(&input, &RunOptions::default());
+                if g.day == 11 && edges == ["cycle"] {
+                    assert!(res.unwrap_err().to_string().contains("infinitely many paths"));
+                } else {
+                    res.unwrap_or_else(|e| panic!("day {} {:?}: {:#}\n{}", g.day, edges, e, input));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! Linear algebra over GF(2), the field with two elements where addition is XOR.
+
+use std::fmt;
+
+/// A fixed-length vector of bits, packed 64 to a word.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl BitVec {
+    pub fn zeros(len: usize) -> Self {
+        BitVec { len, words: vec![0; len.div_ceil(64)] }
+    }
+
+    /// A vector of length `len` with the given positions set.
+    pub fn with_ones(len: usize, ones: impl IntoIterator<Item = usize>) -> Self {
+        let mut v = BitVec::zeros(len);
+        for i in ones {
+            v.set(i, true);
+        }
+        v
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} out of range for length {}", i, self.len);
+        (self.words[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.len, "bit {} out of range for length {}", i, self.len);
+        let mask = 1u64 << (i % 64);
+        if value {
+            self.words[i / 64] |= mask;
+        } else {
+            self.words[i / 64] &= !mask;
+        }
+    }
+
+    /// Adds `other` to this vector, bit by bit.
+    pub fn xor_assign(&mut self, other: &BitVec) {
+        debug_assert_eq!(self.len, other.len);
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= *b;
+        }
+    }
+
+    /// The inner product: parity of the common ones.
+    pub fn dot(&self, other: &BitVec) -> bool {
+        self.words.iter().zip(&other.words).map(|(a, b)| (a & b).count_ones()).sum::<u32>() % 2 == 1
+    }
+
+    /// Hamming weight.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Positions of the set bits, ascending.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(move |&i| self.get(i))
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (0..self.len).try_for_each(|i| f.write_str(if self.get(i) { "1" } else { "0" }))
+    }
+}
+
+/// A dense matrix over GF(2), stored as packed rows.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitMatrix {
+    cols: usize,
+    rows: Vec<BitVec>,
+}
+
+impl BitMatrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        BitMatrix { cols, rows: vec![BitVec::zeros(cols); rows] }
+    }
+
+    /// Builds a matrix from rows, which must all have length `cols`.
+    pub fn from_rows(cols: usize, rows: Vec<BitVec>) -> Self {
+        assert!(rows.iter().all(|r| r.len() == cols), "every row must have {} columns", cols);
+        BitMatrix { cols, rows }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, r: usize) -> &BitVec {
+        &self.rows[r]
+    }
+
+    pub fn get(&self, r: usize, c: usize) -> bool {
+        self.rows[r].get(c)
+    }
+
+    pub fn set(&mut self, r: usize, c: usize, value: bool) {
+        self.rows[r].set(c, value)
+    }
+
+    /// `A x`.
+    pub fn mul_vec(&self, x: &BitVec) -> BitVec {
+        BitVec::with_ones(self.rows(), (0..self.rows()).filter(|&r| self.rows[r].dot(x)))
+    }
+
+    /// Brings the matrix to reduced row echelon form in place and returns the pivot column
+    /// of each non-zero row; row `i` of the result has its leading one in `pivots[i]`.
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        self.reduce_with(&mut [])
+    }
+
+    /// Row reduction that applies every row operation to `rhs` as well.
+    fn reduce_with(&mut self, rhs: &mut [bool]) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for c in 0..self.cols {
+            let r = pivots.len();
+            let Some(sel) = (r..self.rows()).find(|&i| self.rows[i].get(c)) else { continue };
+            self.rows.swap(r, sel);
+            if !rhs.is_empty() {
+                rhs.swap(r, sel);
+            }
+            let pivot = self.rows[r].clone();
+            for i in 0..self.rows() {
+                if i != r && self.rows[i].get(c) {
+                    self.rows[i].xor_assign(&pivot);
+                    if !rhs.is_empty() {
+                        rhs[i] ^= rhs[r];
+                    }
+                }
+            }
+            pivots.push(c);
+            if pivots.len() == self.rows() {
+                break;
+            }
+        }
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// All solutions of `A x = b`, or `None` if the system is inconsistent.
+    pub fn solve(&self, b: &BitVec) -> Option<SolutionSpace> {
+        assert_eq!(b.len(), self.rows(), "right-hand side must have one bit per row");
+        let mut m = self.clone();
+        let mut rhs: Vec<bool> = (0..b.len()).map(|i| b.get(i)).collect();
+        let pivots = m.reduce_with(&mut rhs);
+        if rhs[pivots.len()..].iter().any(|&bit| bit) {
+            return None;
+        }
+
+        let particular = BitVec::with_ones(self.cols, pivots.iter().enumerate().filter(|&(r, _)| rhs[r]).map(|(_, &c)| c));
+        let mut is_pivot = vec![false; self.cols];
+        for &c in &pivots {
+            is_pivot[c] = true;
+        }
+        let basis = (0..self.cols)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let pivot_ones = pivots.iter().enumerate().filter(|&(r, _)| m.rows[r].get(f)).map(|(_, &c)| c);
+                BitVec::with_ones(self.cols, pivot_ones.chain([f]))
+            })
+            .collect();
+        Some(SolutionSpace { particular, basis })
+    }
+}
+
+/// The solutions of a linear system: `particular` plus any sum of `basis` vectors.
+///
+/// The basis spans the null space and is in systematic form: each vector has exactly one
+/// one among the free (non-pivot) columns, in a column no other basis vector uses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolutionSpace {
+    pub particular: BitVec,
+    pub basis: Vec<BitVec>,
+}
+
+impl SolutionSpace {
+    /// Dimension of the null space; the system has `2^dimension` solutions.
+    pub fn dimension(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// A solution with the fewest ones.
+    ///
+    /// Combining `s` basis vectors sets exactly `s` free columns, so a combination can only
+    /// beat the best solution found so far if `s` is below its weight. The search enumerates
+    /// combinations depth-first and prunes on that bound, so it stays exact and looks at no
+    /// more than `sum(C(d, s) for s < w)` of them, for a null space of dimension `d` and an
+    /// answer of weight `w`. That is polynomial in `d` while the answer is light, but it grows
+    /// exponentially with `w`, up to all `2^d` combinations when `w` is about `d / 2` or more.
+    pub fn min_weight(&self) -> BitVec {
+        let mut best = self.particular.clone();
+        let mut acc = self.particular.clone();
+        self.search(0, 0, &mut acc, &mut best);
+        best
+    }
+
+    fn search(&self, start: usize, used: usize, acc: &mut BitVec, best: &mut BitVec) {
+        if acc.count_ones() < best.count_ones() {
+            best.clone_from(acc);
+        }
+        if used + 1 >= best.count_ones() {
+            return;
+        }
+        for i in start..self.basis.len() {
+            acc.xor_assign(&self.basis[i]);
+            self.search(i + 1, used + 1, acc, best);
+            acc.xor_assign(&self.basis[i]);
+            if used + 1 >= best.count_ones() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: &[&str]) -> BitMatrix {
+        let cols = rows[0].len();
+        BitMatrix::from_rows(cols, rows.iter().map(|r| BitVec::with_ones(cols, r.match_indices('1').map(|(i, _)| i))).collect())
+    }
+
+    /// Tiny deterministic generator so the random checks are reproducible.
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *seed >> 33
+    }
+
+    #[test]
+    fn reduce_and_rank() {
+        let mut m = matrix(&["110", "011", "101"]);
+        assert_eq!(2, m.rank());
+        assert_eq!(vec![0, 1], m.row_reduce());
+        assert_eq!(matrix(&["101", "011", "000"]), m);
+    }
+
+    #[test]
+    fn solutions_and_null_space() {
+        let a = matrix(&["1100", "0110", "0011"]);
+        let b = BitVec::with_ones(3, [0, 2]);
+        let space = a.solve(&b).unwrap();
+        assert_eq!(1, space.dimension());
+        assert_eq!(b, a.mul_vec(&space.particular));
+        for v in &space.basis {
+            assert!(a.mul_vec(v).is_zero());
+        }
+        assert_eq!(2, space.min_weight().count_ones());
+
+        let inconsistent = matrix(&["11", "11"]);
+        assert!(inconsistent.solve(&BitVec::with_ones(2, [0])).is_none());
+    }
+
+    #[test]
+    fn min_weight_matches_brute_force() {
+        let mut seed = 7;
+        for _ in 0..300 {
+            let (rows, cols) = (1 + lcg(&mut seed) as usize % 6, 1 + lcg(&mut seed) as usize % 10);
+            let a = BitMatrix::from_rows(
+                cols,
+                (0..rows).map(|_| BitVec::with_ones(cols, (0..cols).filter(|_| lcg(&mut seed).is_multiple_of(2)))).collect(),
+            );
+            let b = BitVec::with_ones(rows, (0..rows).filter(|_| lcg(&mut seed).is_multiple_of(2)));
+            let brute = (0..1u32 << cols)
+                .map(|mask| BitVec::with_ones(cols, (0..cols).filter(|&i| mask >> i & 1 == 1)))
+                .filter(|x| a.mul_vec(x) == b)
+                .map(|x| x.count_ones())
+                .min();
+            let fast = a.solve(&b).map(|s| {
+                let x = s.min_weight();
+                assert_eq!(b, a.mul_vec(&x));
+                x.count_ones()
+            });
+            assert_eq!(brute, fast);
+        }
+    }
+
+    #[test]
+    fn large_null_space() {
+        // 60 unknowns, 4 equations: a null space of dimension 56, far past brute force
+        let cols = 60;
+        let a = BitMatrix::from_rows(cols, (0..4).map(|r| BitVec::with_ones(cols, (r..cols).step_by(4))).collect());
+        let b = BitVec::with_ones(4, 0..4);
+        let space = a.solve(&b).unwrap();
+        assert_eq!(56, space.dimension());
+        let x = space.min_weight();
+        assert_eq!(b, a.mul_vec(&x));
+        assert_eq!(4, x.count_ones());
+    }
+
+    #[test]
+    fn heavy_answers() {
+        // 16 unknowns forced to 1 and 4 random equations on the other 16: every solution has
+        // more ones than the null space has dimensions, which is the search's worst case, so
+        // check it against every combination of the basis
+        let mut seed = 11;
+        for _ in 0..20 {
+            let cols = 32;
+            let mut forced: Vec<usize> = (0..cols).collect();
+            for i in (1..cols).rev() {
+                forced.swap(i, lcg(&mut seed) as usize % (i + 1));
+            }
+            let (forced, rest) = forced.split_at(16);
+            let mut rows: Vec<BitVec> = forced.iter().map(|&c| BitVec::with_ones(cols, [c])).collect();
+            rows.extend((0..4).map(|_| BitVec::with_ones(cols, rest.iter().copied().filter(|_| lcg(&mut seed).is_multiple_of(2)))));
+            let b = BitVec::with_ones(20, (0..16).chain((16..20).filter(|_| lcg(&mut seed).is_multiple_of(2))));
+            let a = BitMatrix::from_rows(cols, rows);
+            let Some(space) = a.solve(&b) else { continue };
+            let d = space.dimension();
+            let every = (0..1u32 << d).map(|mask| {
+                let mut x = space.particular.clone();
+                (0..d).filter(|&i| mask >> i & 1 == 1).for_each(|i| x.xor_assign(&space.basis[i]));
+                x.count_ones()
+            });
+            let x = space.min_weight();
+            assert_eq!(b, a.mul_vec(&x));
+            assert_eq!(every.min(), Some(x.count_ones()));
+            assert!(x.count_ones() > d, "weight {} in dimension {}", x.count_ones(), d);
+        }
+    }
+}
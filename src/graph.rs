@@ -0,0 +1,548 @@
+use crate::Answer;
+use anyhow::{bail, Result};
+use num_bigint::BigUint;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// A directed graph with named nodes, numbered `0..len()` in order of first appearance.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    adj: Vec<Vec<usize>>,
+}
+
+/// How [`Graph::count_paths`] keeps its counts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Counting {
+    /// Exactly, however many digits that takes.
+    #[default]
+    Exact,
+    /// Modulo a number, usually a large prime, for a fixed-size fingerprint of a huge count.
+    Modulo(u64),
+}
+
+impl Counting {
+    /// No paths, counted this way.
+    pub fn zero(self) -> PathCount {
+        self.count(0)
+    }
+
+    fn count(self, n: u64) -> PathCount {
+        match self {
+            Counting::Exact => PathCount::Exact(BigUint::from(n)),
+            Counting::Modulo(m) => PathCount::Modulo { count: n % m, modulus: m },
+        }
+    }
+}
+
+/// A number of paths, and how it was counted. Displays as the bare number; the mode says
+/// whether it is `exact` or reduced `mod M`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathCount {
+    Exact(BigUint),
+    Modulo { count: u64, modulus: u64 },
+}
+
+impl PathCount {
+    fn add(&mut self, other: &PathCount) {
+        match (self, other) {
+            (PathCount::Exact(a), PathCount::Exact(b)) => *a += b,
+            (PathCount::Modulo { count: a, modulus }, PathCount::Modulo { count: b, .. }) => {
+                *a = ((*a as u128 + *b as u128) % *modulus as u128) as u64
+            }
+            _ => unreachable!("path counts kept in different ways"),
+        }
+    }
+
+    fn mul(&mut self, other: &PathCount) {
+        match (self, other) {
+            (PathCount::Exact(a), PathCount::Exact(b)) => *a *= b,
+            (PathCount::Modulo { count: a, modulus }, PathCount::Modulo { count: b, .. }) => {
+                *a = (*a as u128 * *b as u128 % *modulus as u128) as u64
+            }
+            _ => unreachable!("path counts kept in different ways"),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            PathCount::Exact(n) => *n == BigUint::ZERO,
+            PathCount::Modulo { count, .. } => *count == 0,
+        }
+    }
+}
+
+impl fmt::Display for PathCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathCount::Exact(n) => write!(f, "{}", n),
+            PathCount::Modulo { count, .. } => write!(f, "{}", count),
+        }
+    }
+}
+
+impl Answer for PathCount {
+    fn mode(&self) -> Option<String> {
+        match self {
+            PathCount::Exact(_) => Some("exact".to_string()),
+            PathCount::Modulo { modulus, .. } => Some(format!("mod {}", modulus)),
+        }
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `name`, adding it as a new node if it is not in the graph yet.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adj.push(Vec::new());
+        id
+    }
+
+    /// The id of `name`, if it is a node.
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.adj[from].push(to);
+    }
+
+    /// The nodes `id` has an edge to, in insertion order.
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.adj[id]
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.adj.iter().enumerate().flat_map(|(u, vs)| vs.iter().map(move |&v| (u, v)))
+    }
+
+    /// Every node before all the nodes it has edges to, or `None` if the graph has a cycle.
+    pub fn topo_sort(&self) -> Option<Vec<usize>> {
+        // Kahn's algorithm: nodes on a cycle never become ready
+        let mut indegree = vec![0usize; self.len()];
+        for (_, v) in self.edges() {
+            indegree[v] += 1;
+        }
+        let mut ready: VecDeque<usize> = (0..self.len()).filter(|&u| indegree[u] == 0).collect();
+        let mut order = Vec::new();
+        while let Some(u) = ready.pop_front() {
+            order.push(u);
+            for &v in &self.adj[u] {
+                indegree[v] -= 1;
+                if indegree[v] == 0 {
+                    ready.push_back(v);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Strongly connected components (Tarjan), each listed sinks first: no component has an
+    /// edge into one that comes after it.
+    pub fn sccs(&self) -> Vec<Vec<usize>> {
+        self.tarjan(&vec![true; self.len()], None)
+    }
+
+    /// Tarjan's algorithm on the nodes marked in `keep`, ignoring the out-edges of `sink`.
+    fn tarjan(&self, keep: &[bool], sink: Option<usize>) -> Vec<Vec<usize>> {
+        const UNSEEN: usize = usize::MAX;
+        let n = self.len();
+        let (mut index, mut low) = (vec![UNSEEN; n], vec![0; n]);
+        let mut on_stack = vec![false; n];
+        let (mut stack, mut sccs, mut next) = (Vec::new(), Vec::new(), 0);
+        for root in 0..n {
+            if !keep[root] || index[root] != UNSEEN {
+                continue;
+            }
+            // explicit call stack of (node, next neighbour to look at)
+            let mut calls = vec![(root, 0)];
+            while let Some(&(u, i)) = calls.last() {
+                if i == 0 {
+                    index[u] = next;
+                    low[u] = next;
+                    next += 1;
+                    stack.push(u);
+                    on_stack[u] = true;
+                }
+                let edge = if Some(u) == sink { None } else { self.adj[u].get(i) };
+                if let Some(&v) = edge {
+                    calls.last_mut().unwrap().1 += 1;
+                    if !keep[v] {
+                        continue;
+                    }
+                    if index[v] == UNSEEN {
+                        calls.push((v, 0));
+                    } else if on_stack[v] {
+                        low[u] = low[u].min(index[v]);
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[u]);
+                }
+                if low[u] == index[u] {
+                    let mut scc = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        scc.push(w);
+                        if w == u {
+                            break;
+                        }
+                    }
+                    scc.sort_unstable();
+                    sccs.push(scc);
+                }
+            }
+        }
+        sccs
+    }
+
+    /// A cycle inside the strongly connected component `scc`, as the nodes along it from its
+    /// smallest node back to that node, or `None` if the component is a single node without
+    /// a loop. Out-edges of `sink` are ignored.
+    pub fn cycle_in(&self, scc: &[usize], sink: Option<usize>) -> Option<Vec<usize>> {
+        let start = *scc.iter().min()?;
+        let mut inside = vec![false; self.len()];
+        for &u in scc {
+            inside[u] = true;
+        }
+        // breadth-first from the start, so the cycle found is a shortest one through it
+        let mut parent = vec![usize::MAX; self.len()];
+        let mut todo = VecDeque::from([start]);
+        while let Some(u) = todo.pop_front() {
+            if Some(u) == sink {
+                continue;
+            }
+            for &v in &self.adj[u] {
+                if v == start {
+                    let mut cycle = vec![start, u];
+                    while *cycle.last().unwrap() != start {
+                        cycle.push(parent[*cycle.last().unwrap()]);
+                    }
+                    cycle.reverse();
+                    // a loop on the start node comes out as [start, start, start]
+                    cycle.dedup();
+                    if cycle.len() == 1 {
+                        cycle.push(start);
+                    }
+                    return Some(cycle);
+                }
+                if inside[v] && parent[v] == usize::MAX {
+                    parent[v] = u;
+                    todo.push_back(v);
+                }
+            }
+        }
+        None
+    }
+
+    /// Nodes reachable from `start` along edges (or, with `reverse`, against them), never
+    /// using an edge out of `stop`.
+    fn reachable(&self, start: usize, stop: Option<usize>, reverse: bool) -> Vec<bool> {
+        let mut incoming = vec![Vec::new(); if reverse { self.len() } else { 0 }];
+        for (u, v) in self.edges().filter(|_| reverse) {
+            incoming[v].push(u);
+        }
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        let mut todo = vec![start];
+        while let Some(u) = todo.pop() {
+            if !reverse && Some(u) == stop {
+                continue;
+            }
+            let next = if reverse { &incoming[u] } else { &self.adj[u] };
+            for &v in next {
+                if reverse && Some(v) == stop {
+                    continue;
+                }
+                if !seen[v] {
+                    seen[v] = true;
+                    todo.push(v);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Number of paths from `from` to `to` that pass through every node of `via`, in any
+    /// order, kept as `counting` says. A path ends the first time it reaches `to`.
+    ///
+    /// The nodes that lie on some `from`-`to` path are condensed into strongly connected
+    /// components, whose topological order is the only order the waypoints can be visited in.
+    /// The count is then the product of the counts between consecutive waypoints. If every
+    /// step has a path and one of them can go round a cycle, there are infinitely many paths,
+    /// which is an error naming the cycle; cycles no such path can reach do not matter.
+    pub fn count_paths(&self, from: usize, to: usize, via: &[usize], counting: Counting) -> Result<PathCount> {
+        if counting == Counting::Modulo(0) {
+            bail!("cannot count paths modulo 0");
+        }
+        let relevant = self.between(from, to, to);
+        if !relevant[from] || via.iter().any(|&w| !relevant[w]) {
+            return Ok(counting.zero());
+        }
+        let mut position = vec![usize::MAX; self.len()];
+        for (i, scc) in self.tarjan(&relevant, Some(to)).iter().rev().enumerate() {
+            for &u in scc {
+                position[u] = i;
+            }
+        }
+        let mut stops: Vec<usize> = via.to_vec();
+        stops.sort_unstable_by_key(|&w| (position[w], w));
+        stops.dedup();
+
+        let mut total = counting.count(1);
+        let mut cycle = None;
+        let mut at = from;
+        for next in stops.into_iter().chain([to]) {
+            // a count that is 0 modulo something still has paths, so ask whether there are any
+            match self.paths_between(at, next, to, counting) {
+                None => return Ok(counting.zero()),
+                Some(Ok(count)) => total.mul(&count),
+                Some(Err(c)) => cycle = cycle.or(Some(c)),
+            }
+            at = next;
+        }
+        if let Some(cycle) = cycle {
+            let names: Vec<&str> = cycle.iter().map(|&u| self.name(u)).collect();
+            bail!(
+                "infinitely many paths from {} to {}: they can go round the cycle {}",
+                self.name(from),
+                self.name(to),
+                names.join(" -> ")
+            );
+        }
+        Ok(total)
+    }
+
+    /// Nodes on some path from `start` to `end` that does not go on past `stop`.
+    fn between(&self, start: usize, end: usize, stop: usize) -> Vec<bool> {
+        let forward = self.reachable(start, Some(stop), false);
+        let backward = self.reachable(end, Some(stop), true);
+        forward.iter().zip(&backward).map(|(&f, &b)| f && b).collect()
+    }
+
+    /// Paths from `start` to `end` that do not go on past `stop`: `None` if there are none,
+    /// and otherwise their number or a cycle that they can go round.
+    fn paths_between(&self, start: usize, end: usize, stop: usize, counting: Counting) -> Option<Result<PathCount, Vec<usize>>> {
+        let keep = self.between(start, end, stop);
+        if !keep[start] {
+            return None;
+        }
+        let sccs = self.tarjan(&keep, Some(stop));
+        if let Some(cycle) = sccs.iter().find_map(|scc| self.cycle_in(scc, Some(stop))) {
+            return Some(Err(cycle));
+        }
+        // every component is a single node, listed sinks first
+        let mut ways = vec![counting.zero(); self.len()];
+        ways[start] = counting.count(1);
+        for u in sccs.iter().rev().map(|scc| scc[0]) {
+            if u == end || u == stop || ways[u].is_zero() {
+                continue;
+            }
+            let here = std::mem::replace(&mut ways[u], counting.zero());
+            for &v in self.adj[u].iter().filter(|&&v| keep[v]) {
+                ways[v].add(&here);
+            }
+        }
+        Some(Ok(std::mem::replace(&mut ways[end], counting.zero())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+
+    fn graph(edges: &[(&str, &str)]) -> Graph {
+        let mut g = Graph::new();
+        for &(a, b) in edges {
+            let (a, b) = (g.intern(a), g.intern(b));
+            g.add_edge(a, b);
+        }
+        g
+    }
+
+    #[test]
+    fn interning_and_order() {
+        let g = graph(&[("c", "a"), ("a", "b"), ("c", "b")]);
+        assert_eq!(vec!["c", "a", "b"], (0..g.len()).map(|i| g.name(i)).collect::<Vec<_>>());
+        assert_eq!(Some(1), g.id("a"));
+        assert_eq!(None, g.id("z"));
+        assert_eq!(Some(vec![0, 1, 2]), g.topo_sort());
+        assert_eq!(None, graph(&[("a", "b"), ("b", "a")]).topo_sort());
+    }
+
+    #[test]
+    fn strongly_connected_components() {
+        let g = graph(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d"), ("d", "e"), ("e", "d"), ("f", "a")]);
+        assert_eq!(vec![vec![3, 4], vec![0, 1, 2], vec![5]], g.sccs());
+    }
+
+    /// Exact number of paths between the named nodes, as text.
+    fn paths(g: &Graph, from: &str, to: &str, via: &[&str]) -> Result<String> {
+        let id = |n| g.id(n).unwrap();
+        let via: Vec<usize> = via.iter().map(|&n| id(n)).collect();
+        g.count_paths(id(from), id(to), &via, Counting::Exact).map(|c| c.to_string())
+    }
+
+    #[test]
+    fn paths_through_waypoints() {
+        let g = graph(&[("s", "a"), ("s", "b"), ("a", "c"), ("b", "c"), ("c", "t"), ("a", "t"), ("t", "u"), ("u", "t")]);
+        // t and u form a cycle, but paths stop at t
+        assert_eq!("3", paths(&g, "s", "t", &[]).unwrap());
+        assert_eq!("2", paths(&g, "s", "t", &["c"]).unwrap());
+        assert_eq!("1", paths(&g, "s", "t", &["c", "b"]).unwrap());
+        assert_eq!("0", paths(&g, "s", "t", &["a", "b"]).unwrap());
+        assert_eq!("0", paths(&g, "c", "a", &[]).unwrap());
+
+        let cyclic = graph(&[("s", "a"), ("a", "b"), ("b", "a"), ("b", "t"), ("s", "x"), ("x", "x")]);
+        let err = paths(&cyclic, "s", "t", &[]).unwrap_err();
+        assert_eq!("infinitely many paths from s to t: they can go round the cycle a -> b -> a", err.to_string());
+        // a cycle that cannot reach the target does not matter
+        assert_eq!("1", paths(&cyclic, "s", "x", &[]).unwrap());
+    }
+
+    #[test]
+    fn cycles_off_the_waypoint_route() {
+        let g = graph(&[("s", "a"), ("a", "t"), ("s", "c"), ("c", "d"), ("d", "e"), ("e", "c"), ("d", "t")]);
+        let err = paths(&g, "s", "t", &[]).unwrap_err().to_string();
+        assert!(err.ends_with("the cycle c -> d -> e -> c"), "{}", err);
+        assert_eq!("1", paths(&g, "s", "t", &["a"]).unwrap());
+        assert!(paths(&g, "s", "t", &["e"]).is_err());
+        assert_eq!("0", paths(&g, "s", "t", &["a", "e"]).unwrap());
+        assert_eq!(Some(vec![0, 0]), graph(&[("d", "d")]).cycle_in(&[0], None));
+        assert_eq!(None, g.cycle_in(&[g.id("a").unwrap()], None));
+    }
+
+    /// `n` diamonds in a row, so there are `2^n` paths from `d0` to the last node.
+    fn diamonds(n: usize) -> Graph {
+        let mut edges = Vec::new();
+        for i in 0..n {
+            let (d, l, r, e) = (format!("d{}", i), format!("l{}", i), format!("r{}", i), format!("d{}", i + 1));
+            edges.extend([(d.clone(), l.clone()), (d, r.clone()), (l, e.clone()), (r, e)]);
+        }
+        let edges: Vec<(&str, &str)> = edges.iter().map(|(a, b)| (a.as_str(), b.as_str())).collect();
+        graph(&edges)
+    }
+
+    #[test]
+    fn counts_beyond_u128() {
+        let g = diamonds(200);
+        let end = "d200";
+        assert_eq!((BigUint::from(1u8) << 200u32).to_string(), paths(&g, "d0", end, &[]).unwrap());
+        assert_eq!((BigUint::from(1u8) << 199u32).to_string(), paths(&g, "d0", end, &["l7"]).unwrap());
+
+        let p = 1_000_000_007u64;
+        let modular = g.count_paths(0, g.id(end).unwrap(), &[], Counting::Modulo(p)).unwrap();
+        let want = BigUint::from(2u8).modpow(&BigUint::from(200u8), &BigUint::from(p));
+        assert_eq!((want.to_string(), Some(format!("mod {}", p))), (modular.to_string(), modular.mode()));
+        assert!(g.count_paths(0, 1, &[], Counting::Modulo(0)).is_err());
+    }
+
+    #[test]
+    fn modular_counts_of_zero_still_see_cycles() {
+        // two paths to the waypoint are 0 modulo 2, but the cycle after it still counts
+        let mut g = diamonds(1);
+        let (d1, x, y, t) = (g.id("d1").unwrap(), g.intern("x"), g.intern("y"), g.intern("t"));
+        for (a, b) in [(d1, x), (x, y), (y, x), (y, t)] {
+            g.add_edge(a, b);
+        }
+        let err = g.count_paths(0, t, &[d1], Counting::Modulo(2)).unwrap_err().to_string();
+        assert!(err.ends_with("the cycle x -> y -> x"), "{}", err);
+        let mut g = diamonds(1);
+        let t = g.intern("t");
+        g.add_edge(g.id("d1").unwrap(), t);
+        assert_eq!("0", g.count_paths(0, t, &[], Counting::Modulo(2)).unwrap().to_string());
+    }
+
+    /// Walks from `from` that stop at `to` and have seen every node of `via`, by length up to
+    /// `max_len`; `None` if there are more the longer they may be.
+    fn count_walks(g: &Graph, from: usize, to: usize, via: &[usize]) -> Option<String> {
+        let full = (1usize << via.len()) - 1;
+        let seen = |u: usize| via.iter().enumerate().filter(|&(_, &w)| w == u).fold(0, |m, (i, _)| m | 1 << i);
+        let walks = |max_len: usize| {
+            let mut now = vec![vec![0u128; full + 1]; g.len()];
+            now[from][seen(from)] = 1;
+            let mut done = 0;
+            for _ in 0..=max_len {
+                let mut next = vec![vec![0u128; full + 1]; g.len()];
+                for (u, row) in now.iter().enumerate() {
+                    for (mask, &c) in row.iter().enumerate() {
+                        if c == 0 {
+                            continue;
+                        }
+                        if u == to {
+                            done += if mask == full { c } else { 0 };
+                            continue;
+                        }
+                        for &v in g.neighbors(u) {
+                            next[v][mask | seen(v)] += c;
+                        }
+                    }
+                }
+                now = next;
+            }
+            done
+        };
+        // a walk round a cycle can be made longer by at most len() a lap
+        let (short, long) = (walks(4 * g.len()), walks(6 * g.len()));
+        (short == long).then(|| short.to_string())
+    }
+
+    #[test]
+    fn counts_match_walking() {
+        let gen = |rng: &mut Rng, size: usize| {
+            let n = 2 + size % 5;
+            let names = ["a", "b", "c", "d", "e", "f"];
+            let mut edges = Vec::new();
+            for u in 0..n {
+                for v in 0..n {
+                    if rng.chance(0.3) {
+                        edges.push((names[u], names[v]));
+                    }
+                }
+            }
+            let mut g = Graph::new();
+            for name in &names[..n] {
+                g.intern(name);
+            }
+            for (u, v) in edges {
+                g.add_edge(g.id(u).unwrap(), g.id(v).unwrap());
+            }
+            let via: Vec<usize> = (0..rng.below(3)).map(|_| rng.below(n)).collect();
+            (g, rng.below(n), rng.below(n), via)
+        };
+        let fast = |(g, from, to, via): &(Graph, usize, usize, Vec<usize>)| match g.count_paths(*from, *to, via, Counting::Exact) {
+            Ok(count) => Some(count.to_string()),
+            Err(e) if e.to_string().contains("infinitely many paths") => None,
+            Err(e) => panic!("{}", e),
+        };
+        let naive = |(g, from, to, via): &(Graph, usize, usize, Vec<usize>)| count_walks(g, *from, *to, via);
+        differential(3000, 5, 4, gen, fast, naive).unwrap();
+    }
+}
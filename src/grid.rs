@@ -0,0 +1,217 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A `(row, col)` position in a [`Grid`].
+pub type Point = (usize, usize);
+
+const DIRS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const DIRS8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+/// A dense, row-major 2D grid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from rows of possibly different lengths, padding short rows with `pad`.
+    pub fn from_rows(rows: Vec<Vec<T>>, pad: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, pad.clone());
+            cells.extend(row);
+        }
+        Grid { width, height, cells }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (r, c): Point) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.cells[r * self.width + c])
+    }
+
+    pub fn get_mut(&mut self, (r, c): Point) -> Option<&mut T> {
+        (r < self.height && c < self.width).then(|| &mut self.cells[r * self.width + c])
+    }
+
+    /// `p` moved by `(dr, dc)`, if that is still inside the grid.
+    pub fn offset(&self, (r, c): Point, (dr, dc): (isize, isize)) -> Option<Point> {
+        let r = r.checked_add_signed(dr)?;
+        let c = c.checked_add_signed(dc)?;
+        (r < self.height && c < self.width).then_some((r, c))
+    }
+
+    /// All positions in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(move |r| (0..self.width).map(move |c| (r, c)))
+    }
+
+    /// The in-bounds orthogonal neighbours of `p`.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRS4.iter().filter_map(move |&d| self.offset(p, d))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `p`.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRS8.iter().filter_map(move |&d| self.offset(p, d))
+    }
+
+    /// The first position (row-major) whose cell matches `pred`.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(pred).map(|i| (i / self.width, i % self.width))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|v| v == value)
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |r| self.row(r))
+    }
+
+    pub fn col(&self, c: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).map(move |r| &self.cells[r * self.width + c])
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    fn remap(&self, width: usize, height: usize, src: impl Fn(usize, usize) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(|(r, c)| self[src(r, c)].clone())
+            .collect();
+        Grid { width, height, cells }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |r, c| (c, r))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.remap(self.height, self.width, |r, c| (h - 1 - c, r))
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.remap(self.height, self.width, |r, c| (c, w - 1 - r))
+    }
+}
+
+impl Grid<char> {
+    /// Parses one row per line. Trailing whitespace is dropped and ragged lines are padded
+    /// with spaces to the longest line.
+    pub fn parse(text: &str) -> Self {
+        let rows = text.lines().map(|l| l.trim_end().chars().collect()).collect();
+        Grid::from_rows(rows, ' ')
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Point) -> &T {
+        assert!(r < self.height && c < self.width, "({}, {}) is outside the grid", r, c);
+        &self.cells[r * self.width + c]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Point) -> &mut T {
+        assert!(r < self.height && c < self.width, "({}, {}) is outside the grid", r, c);
+        &mut self.cells[r * self.width + c]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for v in row {
+                write!(f, "{}", v)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pads_ragged_lines() {
+        let g = Grid::parse("ab\nc\ndef  \n");
+        assert_eq!((3, 3), (g.width(), g.height()));
+        assert_eq!("ab \nc  \ndef\n", g.to_string());
+        assert_eq!(Some((2, 1)), g.find(&'e'));
+        assert_eq!(None, g.get((3, 0)));
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let g = Grid::new(3, 2, 0u8);
+        assert_eq!(vec![(0, 1), (1, 0)], g.neighbours4((0, 0)).collect::<Vec<_>>());
+        assert_eq!(3, g.neighbours8((0, 0)).count());
+        assert_eq!(5, g.neighbours8((1, 1)).count());
+    }
+
+    #[test]
+    fn views_and_rotations() {
+        let g = Grid::parse("abc\ndef");
+        assert_eq!(&['d', 'e', 'f'], g.row(1));
+        assert_eq!("cf", g.col(2).collect::<String>());
+        assert_eq!("ad\nbe\ncf\n", g.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", g.rotate_cw().to_string());
+        assert_eq!("cf\nbe\nad\n", g.rotate_ccw().to_string());
+        assert_eq!(g, g.rotate_cw().rotate_ccw());
+    }
+}
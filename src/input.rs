@@ -0,0 +1,108 @@
+use crate::fixtures;
+use anyhow::{bail, Context, Result};
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Overrides the directory day inputs are looked up in.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Where a day's input should come from, as requested on the command line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputSpec {
+    /// An explicit path, or `-` for stdin. Takes precedence over everything else.
+    pub path: Option<String>,
+    /// A named variant: `alt` selects `DD-alt.txt` instead of `DD.txt`.
+    pub variant: Option<String>,
+    /// The puzzle's n-th example from `examples/`, instead of the real input.
+    pub example: Option<usize>,
+}
+
+/// Resolved input text and a description of where it was read from.
+#[derive(Clone, Debug)]
+pub struct Input {
+    pub origin: String,
+    pub text: String,
+}
+
+pub fn file_name(day: u8, variant: Option<&str>) -> String {
+    match variant {
+        Some(v) => format!("{:02}-{}.txt", day, v),
+        None => format!("{:02}.txt", day),
+    }
+}
+
+/// Every location that is checked for a day's input, in order.
+pub fn candidates(day: u8, variant: Option<&str>) -> Vec<PathBuf> {
+    let name = file_name(day, variant);
+    let mut out = Vec::new();
+    if let Ok(dir) = env::var(INPUT_DIR_ENV) {
+        out.push(Path::new(&dir).join(&name));
+    }
+    out.push(Path::new("input").join(&name));
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("input").join(&name);
+    if !out.contains(&manifest) {
+        out.push(manifest);
+    }
+    out
+}
+
+pub fn resolve(day: u8, spec: &InputSpec) -> Result<Input> {
+    match (spec.path.as_deref(), spec.example) {
+        (Some("-"), _) => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).context("failed to read input from stdin")?;
+            Ok(Input { origin: "<stdin>".to_string(), text })
+        }
+        (Some(path), _) => read(Path::new(path)),
+        (None, Some(n)) => {
+            let path = fixtures::example_path(day, n);
+            if !path.is_file() {
+                bail!("day {:02} has no example {} (looked for {})", day, n, path.display());
+            }
+            read(&path)
+        }
+        (None, None) => {
+            let tried = candidates(day, spec.variant.as_deref());
+            match tried.iter().find(|p| p.is_file()) {
+                Some(path) => read(path),
+                None => {
+                    let list: Vec<String> = tried.iter().map(|p| format!("  - {}", p.display())).collect();
+                    bail!("no input found for day {:02}; tried:\n{}", day, list.join("\n"))
+                }
+            }
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<Input> {
+    let text = fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(Input { origin: path.display().to_string(), text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        assert_eq!("05.txt", file_name(5, None));
+        assert_eq!("05-alt.txt", file_name(5, Some("alt")));
+    }
+
+    #[test]
+    fn examples() {
+        let spec = |n| InputSpec { example: Some(n), ..InputSpec::default() };
+        assert!(resolve(11, &spec(2)).unwrap().origin.ends_with("11-2.txt"));
+        assert!(resolve(11, &spec(9)).unwrap_err().to_string().contains("no example 9"));
+    }
+
+    #[test]
+    fn reports_every_location_tried() {
+        let err = resolve(99, &InputSpec::default()).unwrap_err().to_string();
+        for path in candidates(99, None) {
+            assert!(err.contains(&path.display().to_string()), "{}", err);
+        }
+    }
+}
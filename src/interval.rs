@@ -0,0 +1,229 @@
+use std::fmt;
+
+/// An integer type [`IntervalSet`] can hold.
+pub trait Bound: Copy + Ord + fmt::Debug {
+    /// `self + 1`, or `None` at the type's maximum.
+    fn succ(self) -> Option<Self>;
+    /// `self - 1`, or `None` at the type's minimum.
+    fn pred(self) -> Option<Self>;
+    /// The value in a type wide enough to subtract any two values without overflow.
+    fn widen(self) -> i128;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {$(
+        impl Bound for $t {
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+            fn widen(self) -> i128 {
+                self as i128
+            }
+        }
+    )*};
+}
+
+impl_bound!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A set of integers stored as sorted, disjoint, non-adjacent closed intervals `[lo, hi]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Bound> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Bound> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.ranges.iter().map(|(lo, hi)| lo..=hi)).finish()
+    }
+}
+
+impl<T: Bound> FromIterator<(T, T)> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for (lo, hi) in iter {
+            set.insert(lo, hi);
+        }
+        set
+    }
+}
+
+/// Whether `[_, hi]` and `[lo, _]` overlap or touch, so they belong in one interval.
+fn joins<T: Bound>(hi: T, lo: T) -> bool {
+    hi >= lo || hi.succ() == Some(lo)
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The disjoint intervals, in ascending order.
+    pub fn ranges(&self) -> &[(T, T)] {
+        &self.ranges
+    }
+
+    /// Number of disjoint intervals.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds `[lo, hi]`, merging it with every interval it overlaps or touches.
+    pub fn insert(&mut self, lo: T, hi: T) {
+        if lo > hi {
+            return;
+        }
+        let i = self.ranges.partition_point(|&(_, h)| !joins(h, lo));
+        let j = self.ranges.partition_point(|&(l, _)| joins(hi, l));
+        let (mut lo, mut hi) = (lo, hi);
+        if i < j {
+            lo = lo.min(self.ranges[i].0);
+            hi = hi.max(self.ranges[j - 1].1);
+        }
+        self.ranges.splice(i..j, [(lo, hi)]);
+    }
+
+    /// Removes `[lo, hi]`, splitting any interval that sticks out on either side.
+    pub fn remove(&mut self, lo: T, hi: T) {
+        if lo > hi {
+            return;
+        }
+        let i = self.ranges.partition_point(|&(_, h)| h < lo);
+        let j = self.ranges.partition_point(|&(l, _)| l <= hi);
+        if i >= j {
+            return;
+        }
+        let mut keep = Vec::with_capacity(2);
+        if let Some(before) = lo.pred().filter(|&b| self.ranges[i].0 <= b) {
+            keep.push((self.ranges[i].0, before));
+        }
+        if let Some(after) = hi.succ().filter(|&a| a <= self.ranges[j - 1].1) {
+            keep.push((after, self.ranges[j - 1].1));
+        }
+        self.ranges.splice(i..j, keep);
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.contains_range(x, x)
+    }
+
+    /// Whether every value of `[lo, hi]` is in the set; an empty range always is.
+    pub fn contains_range(&self, lo: T, hi: T) -> bool {
+        if lo > hi {
+            return true;
+        }
+        let k = self.ranges.partition_point(|&(l, _)| l <= lo);
+        k > 0 && self.ranges[k - 1].1 >= hi
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for &(lo, hi) in &other.ranges {
+            out.insert(lo, hi);
+        }
+        out
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (0, 0);
+        let mut ranges = Vec::new();
+        while a < self.ranges.len() && b < other.ranges.len() {
+            let (alo, ahi) = self.ranges[a];
+            let (blo, bhi) = other.ranges[b];
+            let (lo, hi) = (alo.max(blo), ahi.min(bhi));
+            if lo <= hi {
+                ranges.push((lo, hi));
+            }
+            if ahi < bhi {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = self.clone();
+        for &(lo, hi) in &other.ranges {
+            out.remove(lo, hi);
+        }
+        out
+    }
+
+    /// Everything in `[lo, hi]` that is not in the set.
+    pub fn complement(&self, lo: T, hi: T) -> Self {
+        let mut all = IntervalSet::new();
+        all.insert(lo, hi);
+        all.difference(self)
+    }
+
+    /// How many integers the set covers. Widened, so even a full `i64` range fits.
+    pub fn total_len(&self) -> u128 {
+        self.ranges.iter().map(|&(lo, hi)| (hi.widen() - lo.widen() + 1) as u128).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().copied().collect()
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let s = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6)]);
+        assert_eq!(&[(3, 6), (10, 20)], s.ranges());
+        assert_eq!(4 + 11, s.total_len());
+        assert!(s.contains(6) && !s.contains(7) && s.contains(20) && !s.contains(21));
+        assert!(s.contains_range(11, 19) && !s.contains_range(5, 10));
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[(1, 10), (20, 30)]);
+        s.remove(5, 22);
+        assert_eq!(&[(1, 4), (23, 30)], s.ranges());
+        s.remove(1, 4);
+        s.remove(40, 50);
+        assert_eq!(&[(23, 30)], s.ranges());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (14, 20)]);
+        assert_eq!(&[(1, 20)], a.union(&b).ranges());
+        assert_eq!(&[(4, 5), (10, 11), (14, 15)], a.intersection(&b).ranges());
+        assert_eq!(&[(1, 3), (12, 13)], a.difference(&b).ranges());
+        assert_eq!(&[(0, 0), (6, 9), (16, 16)], a.complement(0, 16).ranges());
+    }
+
+    #[test]
+    fn extreme_bounds_do_not_overflow() {
+        let mut s = set(&[(i64::MAX - 1, i64::MAX), (i64::MIN, i64::MIN + 1)]);
+        s.insert(i64::MAX, i64::MAX);
+        assert_eq!(&[(i64::MIN, i64::MIN + 1), (i64::MAX - 1, i64::MAX)], s.ranges());
+        s.insert(i64::MIN, i64::MAX);
+        assert_eq!(1u128 << 64, s.total_len());
+        s.remove(i64::MIN, i64::MIN);
+        s.remove(i64::MAX, i64::MAX);
+        assert_eq!(&[(i64::MIN + 1, i64::MAX - 1)], s.ranges());
+        let full: IntervalSet<u8> = [(0, 255)].into_iter().collect();
+        assert!(full.complement(0, 255).is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::union_find::UnionFind;
+use std::collections::BinaryHeap;
+
+/// A point in space.
+pub type Point3 = [i64; 3];
+
+/// Squared Euclidean distance.
+pub fn dist2(a: &Point3, b: &Point3) -> u64 {
+    (0..3).map(|k| a[k].abs_diff(b[k]).pow(2)).sum()
+}
+
+/// A pair of points `(d2, i, j)` with `i < j`. Pairs order by squared distance, then by
+/// index, so every pair is distinct and "the k closest" is always well defined.
+pub type Pair = (u64, usize, usize);
+
+fn pair(d2: u64, a: usize, b: usize) -> Pair {
+    (d2, a.min(b), a.max(b))
+}
+
+/// A k-d tree over points in 3D, for proximity queries without looking at all pairs.
+///
+/// The tree is implicit: the subtree over `order[lo..hi]` has its root at the middle position,
+/// with the points below it on that node's axis to the left and the rest to the right.
+#[derive(Clone, Debug)]
+pub struct KdTree {
+    points: Vec<Point3>,
+    order: Vec<usize>,
+    /// Splitting axis of the node at each position of `order`.
+    axis: Vec<u8>,
+    /// Bounding box of the subtree rooted at each position of `order`.
+    boxes: Vec<(Point3, Point3)>,
+}
+
+/// Marks a subtree whose points are not all in one component.
+const MIXED: usize = usize::MAX;
+
+/// What a search is looking for. Subtrees farther away than `limit` are not visited.
+trait Visitor {
+    fn limit(&self) -> u64;
+    /// Whether the subtree rooted at position `pos` can be skipped without looking at it.
+    fn skip(&self, _pos: usize) -> bool {
+        false
+    }
+    fn offer(&mut self, id: usize, d2: u64);
+}
+
+impl KdTree {
+    pub fn new(points: Vec<Point3>) -> Self {
+        let n = points.len();
+        let mut tree = KdTree { order: (0..n).collect(), axis: vec![0; n], boxes: vec![([0; 3], [0; 3]); n], points };
+        tree.build(0, n);
+        tree
+    }
+
+    pub fn points(&self) -> &[Point3] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Splits `order[lo..hi]` on its widest axis and recurses into both halves.
+    fn build(&mut self, lo: usize, hi: usize) {
+        if lo >= hi {
+            return;
+        }
+        let mut bounds = ([i64::MAX; 3], [i64::MIN; 3]);
+        for &id in &self.order[lo..hi] {
+            for k in 0..3 {
+                bounds.0[k] = bounds.0[k].min(self.points[id][k]);
+                bounds.1[k] = bounds.1[k].max(self.points[id][k]);
+            }
+        }
+        let axis = (0..3).max_by_key(|&k| bounds.1[k].abs_diff(bounds.0[k])).unwrap();
+        let mid = lo + (hi - lo) / 2;
+        let points = &self.points;
+        self.order[lo..hi].select_nth_unstable_by_key(mid - lo, |&id| points[id][axis]);
+        self.axis[mid] = axis as u8;
+        self.boxes[mid] = bounds;
+        self.build(lo, mid);
+        self.build(mid + 1, hi);
+    }
+
+    /// Lower bound on the squared distance from `q` to anything in the subtree at `pos`.
+    fn box_dist2(&self, pos: usize, q: &Point3) -> u64 {
+        let (lo, hi) = &self.boxes[pos];
+        (0..3)
+            .map(|k| match q[k] {
+                x if x < lo[k] => lo[k].abs_diff(x).pow(2),
+                x if x > hi[k] => x.abs_diff(hi[k]).pow(2),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Offers `v` every point in `order[lo..hi]` that may be within its limit of `q`, nearer
+    /// subtrees first so the limit shrinks early.
+    fn search(&self, q: &Point3, lo: usize, hi: usize, v: &mut impl Visitor) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if v.skip(mid) || self.box_dist2(mid, q) > v.limit() {
+            return;
+        }
+        let id = self.order[mid];
+        v.offer(id, dist2(q, &self.points[id]));
+        let axis = self.axis[mid] as usize;
+        let (near, far) = if q[axis] < self.points[id][axis] { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.search(q, near.0, near.1, v);
+        self.search(q, far.0, far.1, v);
+    }
+
+    /// The `k` closest pairs of points, nearest first.
+    pub fn closest_pairs(&self, k: usize) -> Vec<Pair> {
+        struct Closest {
+            from: usize,
+            k: usize,
+            best: BinaryHeap<Pair>,
+        }
+        impl Visitor for Closest {
+            fn limit(&self) -> u64 {
+                if self.best.len() < self.k { u64::MAX } else { self.best.peek().map_or(u64::MAX, |p| p.0) }
+            }
+            fn offer(&mut self, id: usize, d2: u64) {
+                // each pair is found from its smaller end only
+                if id <= self.from {
+                    return;
+                }
+                let candidate = pair(d2, self.from, id);
+                if self.best.len() < self.k {
+                    self.best.push(candidate);
+                } else if self.best.peek().is_some_and(|&worst| candidate < worst) {
+                    self.best.pop();
+                    self.best.push(candidate);
+                }
+            }
+        }
+
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut v = Closest { from: 0, k, best: BinaryHeap::with_capacity(k.min(self.len().saturating_mul(self.len())) + 1) };
+        for &id in &self.order {
+            v.from = id;
+            self.search(&self.points[id], 0, self.len(), &mut v);
+        }
+        v.best.into_sorted_vec()
+    }
+
+    /// The edges of the minimum spanning tree, shortest first: the order in which Kruskal's
+    /// algorithm would join the points.
+    ///
+    /// Borůvka: each round, every component finds its nearest point in another component and
+    /// they are joined. Subtrees lying entirely inside the searching component are skipped,
+    /// so a round costs about as much as one nearest-neighbour query per point.
+    pub fn spanning_tree(&self) -> Vec<Pair> {
+        struct Nearest<'a> {
+            from: usize,
+            component: usize,
+            of: &'a [usize],
+            uniform: &'a [usize],
+            best: Option<Pair>,
+        }
+        impl Visitor for Nearest<'_> {
+            fn limit(&self) -> u64 {
+                self.best.map_or(u64::MAX, |p| p.0)
+            }
+            fn skip(&self, pos: usize) -> bool {
+                self.uniform[pos] == self.component
+            }
+            fn offer(&mut self, id: usize, d2: u64) {
+                let candidate = pair(d2, self.from, id);
+                if self.of[id] != self.component && self.best.is_none_or(|b| candidate < b) {
+                    self.best = Some(candidate);
+                }
+            }
+        }
+
+        let n = self.len();
+        let mut clusters = UnionFind::new(n);
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        let mut of = vec![0; n];
+        let mut uniform = vec![MIXED; n];
+        while clusters.components() > 1 {
+            for (id, c) in of.iter_mut().enumerate() {
+                *c = clusters.find(id);
+            }
+            self.mark_uniform(0, n, &of, &mut uniform);
+            let mut best: Vec<Option<Pair>> = vec![None; n];
+            for &id in &self.order {
+                let component = of[id];
+                let mut v = Nearest { from: id, component, of: &of, uniform: &uniform, best: best[component] };
+                self.search(&self.points[id], 0, n, &mut v);
+                best[component] = v.best;
+            }
+            for edge in best.into_iter().flatten() {
+                if clusters.union(edge.1, edge.2) {
+                    edges.push(edge);
+                }
+            }
+        }
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Records, for every subtree, the component all its points are in, or `MIXED`.
+    fn mark_uniform(&self, lo: usize, hi: usize, of: &[usize], uniform: &mut [usize]) -> Option<usize> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let own = of[self.order[mid]];
+        let left = self.mark_uniform(lo, mid, of, uniform);
+        let right = self.mark_uniform(mid + 1, hi, of, uniform);
+        let same = [left, right].into_iter().flatten().all(|c| c == own);
+        uniform[mid] = if same { own } else { MIXED };
+        Some(uniform[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::Rng;
+    use crate::oracle::differential;
+
+    fn all_pairs(points: &[Point3]) -> Vec<Pair> {
+        let mut pairs = Vec::new();
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                pairs.push((dist2(&points[i], &points[j]), i, j));
+            }
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+
+    fn kruskal(points: &[Point3]) -> Vec<Pair> {
+        let mut clusters = UnionFind::new(points.len());
+        all_pairs(points).into_iter().filter(|p| clusters.union(p.1, p.2)).collect()
+    }
+
+    /// Small coordinates, so distances tie and points coincide.
+    fn points(rng: &mut Rng, size: usize) -> Vec<Point3> {
+        (0..size).map(|_| [rng.range(0, 4), rng.range(-3, 3), rng.range(0, 2 + size as i64)]).collect()
+    }
+
+    #[test]
+    fn closest_pairs_match_sorting_all_pairs() {
+        let gen = |rng: &mut Rng, size: usize| {
+            let k = rng.below(size * size / 2 + 2);
+            (points(rng, size), k)
+        };
+        let naive = |(pts, k): &(Vec<Point3>, usize)| all_pairs(pts).into_iter().take(*k).collect::<Vec<_>>();
+        differential(2000, 40, 1, gen, |(pts, k)| KdTree::new(pts.clone()).closest_pairs(*k), naive).unwrap();
+    }
+
+    #[test]
+    fn spanning_tree_matches_kruskal() {
+        differential(2000, 60, 2, points, |pts| KdTree::new(pts.clone()).spanning_tree(), |pts| kruskal(pts)).unwrap();
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert!(KdTree::new(vec![]).closest_pairs(3).is_empty());
+        assert!(KdTree::new(vec![[1, 2, 3]]).spanning_tree().is_empty());
+        let same = KdTree::new(vec![[5, 5, 5]; 4]);
+        assert_eq!(vec![(0, 0, 1), (0, 0, 2), (0, 0, 3)], same.spanning_tree());
+        assert_eq!(vec![(0, 0, 1), (0, 0, 2)], same.closest_pairs(2));
+    }
+}
@@ -1,7 +1,90 @@
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Display;
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod fixtures;
+pub mod gen;
+pub mod gf2;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod kdtree;
+pub mod lp;
+pub mod normalize;
+pub mod oracle;
+pub mod params;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+pub mod union_find;
+
+pub use graph::Graph;
+pub use grid::{Grid, Point};
+pub use interval::IntervalSet;
+pub use normalize::Normalize;
+pub use params::NoParams;
+pub use parse::ParseError;
+pub use runner::{run_main, DayResult, RunOptions};
+pub use union_find::UnionFind;
+
 pub fn start_day(day: &str) {
     println!("Advent of Code 2025 - Day {:0>2}", day);
 }
 
+/// A single day's puzzle: parse the raw input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+    /// The last day of the year only has one part.
+    const HAS_PART2: bool = true;
+    /// How the raw input is cleaned up before it reaches `parse`.
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+    /// The puzzle's constants, which a run can change (see [`params`]). `Default` gives the
+    /// puzzle's values; days without any use [`NoParams`].
+    type Params: Default + Serialize + DeserializeOwned;
+
+    /// Malformed input is reported as a [`ParseError`] pointing at the offending text.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    /// Parses for a run with other parameters. Days with parameters keep them in their
+    /// `Input`; the others need not implement this.
+    fn parse_with(input: &str, _params: &Self::Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+/// What a part returns. The display is the answer itself; answers that can be computed in
+/// more than one way also name the way, which is shown next to them, and answers with more
+/// to them than a number can add details for the JSON report.
+pub trait Answer: Display {
+    fn mode(&self) -> Option<String> {
+        None
+    }
+    fn details(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Modes that start with this mark an answer that is only a lower bound on the real one,
+/// which `aoc verify --record` will not accept.
+pub const LOWER_BOUND: &str = "lower bound";
+
+macro_rules! plain_answers {
+    ($($t:ty),*) => { $(impl Answer for $t {})* };
+}
+
+plain_answers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String);
+
 // Additional common functions
 
 #[cfg(test)]
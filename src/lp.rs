@@ -0,0 +1,147 @@
+//! Small linear programs, solved with the simplex method on a dense tableau. Meant for the
+//! relaxations inside an integer search, where the programs have tens of variables.
+
+/// Entries smaller than this are treated as zero.
+const EPS: f64 = 1e-9;
+
+/// A tableau in canonical form: every row has a basic column that is 1 in that row and 0 in
+/// the others, and the last entry of each row is the value of its basic column.
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn pivot(&mut self, r: usize, c: usize) {
+        let p = self.rows[r][c];
+        for v in self.rows[r].iter_mut() {
+            *v /= p;
+        }
+        let pivot = self.rows[r].clone();
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            let f = row[c];
+            if i != r && f.abs() > EPS {
+                for (v, &pv) in row.iter_mut().zip(&pivot) {
+                    *v -= f * pv;
+                }
+            }
+        }
+        self.basis[r] = c;
+    }
+
+    fn objective(&self, cost: &[f64]) -> f64 {
+        self.rows.iter().zip(&self.basis).map(|(row, &b)| cost[b] * row[row.len() - 1]).sum()
+    }
+
+    /// Minimises `cost` over the columns `enter` allows into the basis. Bland's rule, so
+    /// degenerate pivots cannot cycle. Returns `false` if the objective is unbounded.
+    fn minimize(&mut self, cost: &[f64], enter: impl Fn(usize) -> bool) -> bool {
+        let width = cost.len();
+        loop {
+            let reduced = |j: usize| cost[j] - self.rows.iter().zip(&self.basis).map(|(row, &b)| cost[b] * row[j]).sum::<f64>();
+            let Some(c) = (0..width).filter(|&j| enter(j)).find(|&j| reduced(j) < -EPS) else { return true };
+            let leave = (0..self.rows.len())
+                .filter(|&i| self.rows[i][c] > EPS)
+                .map(|i| (self.rows[i][width] / self.rows[i][c], self.basis[i], i))
+                .min_by(|a, b| if (a.0 - b.0).abs() <= EPS { a.1.cmp(&b.1) } else { a.0.total_cmp(&b.0) });
+            let Some((_, _, r)) = leave else { return false };
+            self.pivot(r, c);
+        }
+    }
+}
+
+/// Minimises `cost · x` subject to `a x = b` and `lower <= x <= upper`. Returns the optimum
+/// and a point attaining it, or `None` if no `x` satisfies the constraints.
+pub fn minimize(cost: &[f64], a: &[Vec<f64>], b: &[f64], lower: &[f64], upper: &[f64]) -> Option<(f64, Vec<f64>)> {
+    let (m, n) = (a.len(), cost.len());
+    if lower.iter().zip(upper).any(|(lo, hi)| lo > hi) {
+        return None;
+    }
+    // shift to 0 <= y <= upper - lower; columns are y, the slacks of the upper bounds, then
+    // one artificial per equation for the first phase
+    let width = 2 * n + m;
+    let mut rows = Vec::with_capacity(m + n);
+    for (i, row) in a.iter().enumerate() {
+        let rhs = b[i] - row.iter().zip(lower).map(|(c, lo)| c * lo).sum::<f64>();
+        let sign = if rhs < 0.0 { -1.0 } else { 1.0 };
+        let mut t = vec![0.0; width + 1];
+        for (j, &c) in row.iter().enumerate() {
+            t[j] = sign * c;
+        }
+        t[2 * n + i] = 1.0;
+        t[width] = sign * rhs;
+        rows.push(t);
+    }
+    for j in 0..n {
+        let mut t = vec![0.0; width + 1];
+        t[j] = 1.0;
+        t[n + j] = 1.0;
+        t[width] = upper[j] - lower[j];
+        rows.push(t);
+    }
+    let mut tableau = Tableau { rows, basis: (2 * n..width).chain(n..2 * n).collect() };
+
+    let artificial = |j: usize| j >= 2 * n;
+    let phase1: Vec<f64> = (0..width).map(|j| if artificial(j) { 1.0 } else { 0.0 }).collect();
+    tableau.minimize(&phase1, |_| true);
+    if tableau.objective(&phase1) > 1e-7 {
+        return None;
+    }
+    // drive the artificials still in the basis (at zero) out of it; a row where that is
+    // impossible is a redundant equation and stays as it is
+    for r in 0..m + n {
+        if artificial(tableau.basis[r]) {
+            if let Some(c) = (0..2 * n).find(|&j| tableau.rows[r][j].abs() > EPS) {
+                tableau.pivot(r, c);
+            }
+        }
+    }
+
+    let phase2: Vec<f64> = (0..width).map(|j| if j < n { cost[j] } else { 0.0 }).collect();
+    if !tableau.minimize(&phase2, |j| !artificial(j)) {
+        return None;
+    }
+    let mut x = lower.to_vec();
+    for (row, &col) in tableau.rows.iter().zip(&tableau.basis) {
+        if col < n {
+            x[col] += row[width];
+        }
+    }
+    let value = cost.iter().zip(&x).map(|(c, v)| c * v).sum();
+    Some((value, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn optimum_and_point() {
+        // x + y = 4, x - y = 1 leaves one point
+        let (value, x) = minimize(&[1.0, 1.0], &[vec![1.0, 1.0], vec![1.0, -1.0]], &[4.0, 1.0], &[0.0; 2], &[9.0; 2]).unwrap();
+        assert!(close(4.0, value) && close(2.5, x[0]) && close(1.5, x[1]), "{} {:?}", value, x);
+
+        // x + 2y + z = 6 is cheapest through y, unless y is capped
+        let a = [vec![1.0, 2.0, 1.0]];
+        let (value, _) = minimize(&[1.0, 1.0, 1.0], &a, &[6.0], &[0.0; 3], &[9.0; 3]).unwrap();
+        assert!(close(3.0, value), "{}", value);
+        let (value, x) = minimize(&[1.0, 1.0, 1.0], &a, &[6.0], &[0.0; 3], &[9.0, 2.0, 9.0]).unwrap();
+        assert!(close(4.0, value) && close(2.0, x[1]), "{} {:?}", value, x);
+        let (value, x) = minimize(&[1.0, 1.0, 1.0], &a, &[6.0], &[1.0, 0.0, 1.0], &[9.0; 3]).unwrap();
+        assert!(close(4.0, value) && close(2.0, x[1]), "{} {:?}", value, x);
+    }
+
+    #[test]
+    fn infeasible_and_redundant() {
+        assert_eq!(None, minimize(&[1.0], &[vec![1.0]], &[5.0], &[0.0], &[4.0]));
+        assert_eq!(None, minimize(&[1.0, 1.0], &[vec![1.0, 1.0], vec![1.0, 1.0]], &[2.0, 3.0], &[0.0; 2], &[9.0; 2]));
+        assert_eq!(None, minimize(&[1.0], &[], &[], &[3.0], &[2.0]));
+        // the same equation twice
+        let (value, _) = minimize(&[1.0, 2.0], &[vec![1.0, 1.0], vec![2.0, 2.0]], &[3.0, 6.0], &[0.0; 2], &[9.0; 2]).unwrap();
+        assert!(close(3.0, value), "{}", value);
+    }
+}
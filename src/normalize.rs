@@ -0,0 +1,104 @@
+/// Which optional clean-ups [`normalize`] applies; the others always run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Normalize {
+    /// Drop whitespace-only lines at the end, keeping a single final newline.
+    pub trim_trailing_blank_lines: bool,
+}
+
+impl Normalize {
+    pub const DEFAULT: Normalize = Normalize { trim_trailing_blank_lines: true };
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize::DEFAULT
+    }
+}
+
+/// Cleans up raw puzzle input before a day parses it: strips a byte order mark, turns CRLF
+/// and lone CR into LF, blanks a surrounding markdown code fence and, if asked, trims
+/// trailing blank lines.
+pub fn normalize(text: &str, opts: Normalize) -> String {
+    let text = strip_fences(&normalize_line_endings(strip_bom(text)));
+    if opts.trim_trailing_blank_lines {
+        trim_trailing_blank_lines(&text).to_string()
+    } else {
+        text
+    }
+}
+
+pub fn strip_bom(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text)
+}
+
+pub fn normalize_line_endings(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Blanks the opening and closing lines of a code fence (```` ``` ```` with an optional
+/// language) around the whole input. The lines are emptied rather than removed, so line
+/// numbers in parse errors still match the file.
+pub fn strip_fences(text: &str) -> String {
+    let mut lines: Vec<&str> = text.split('\n').collect();
+    let is_fence = |l: &str| l.trim_start().starts_with("```");
+    let first = lines.iter().position(|l| !l.trim().is_empty());
+    if let Some(first) = first.filter(|&i| is_fence(lines[i])) {
+        lines[first] = "";
+        if let Some(last) = lines.iter().rposition(|l| !l.trim().is_empty()).filter(|&i| is_fence(lines[i])) {
+            lines[last] = "";
+        }
+    }
+    lines.join("\n")
+}
+
+/// Drops whitespace-only lines at the end; non-empty input keeps one final newline.
+pub fn trim_trailing_blank_lines(text: &str) -> &str {
+    let content = text.trim_end();
+    if content.is_empty() {
+        return content;
+    }
+    match text[content.len()..].find('\n') {
+        Some(nl) => &text[..content.len() + nl + 1],
+        None => content,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bom() {
+        assert_eq!("abc", strip_bom("\u{feff}abc"));
+        assert_eq!("abc\u{feff}", strip_bom("abc\u{feff}"));
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!("a\nb\nc\n", normalize_line_endings("a\r\nb\rc\n"));
+    }
+
+    #[test]
+    fn fences() {
+        assert_eq!("\n1,2\n3,4\n\n", strip_fences("```text\n1,2\n3,4\n```\n"));
+        assert_eq!("\n\n1\n", strip_fences("\n```\n1\n"));
+        // only a fence around the whole input counts
+        assert_eq!("1\n```\n2\n", strip_fences("1\n```\n2\n"));
+    }
+
+    #[test]
+    fn trailing_blank_lines() {
+        assert_eq!("a\n  b\n", trim_trailing_blank_lines("a\n  b\n\n  \n"));
+        assert_eq!("a", trim_trailing_blank_lines("a  "));
+        assert_eq!("*  +\n", trim_trailing_blank_lines("*  +\n"));
+        assert_eq!("", trim_trailing_blank_lines("\n\n"));
+    }
+
+    #[test]
+    fn all_steps() {
+        let raw = "\u{feff}```\r\n123 4\r\n*   +\r\n```\r\n\r\n";
+        assert_eq!("\n123 4\n*   +\n", normalize(raw, Normalize::DEFAULT));
+        let keep = Normalize { trim_trailing_blank_lines: false };
+        assert_eq!("\n123 4\n*   +\n\n\n", normalize(raw, keep));
+    }
+}
@@ -0,0 +1,74 @@
+//! Differential testing: run an optimised function and a naive reference side by side on
+//! random inputs and report the smallest input they disagree on.
+
+use crate::gen::Rng;
+use anyhow::{bail, Result};
+use std::fmt::Debug;
+
+/// Inputs drawn at each smaller size while shrinking a disagreement.
+const SHRINK_TRIES: usize = 500;
+
+/// Compares `fast` against `naive` on `cases` inputs drawn by `gen`. The size passed to `gen`
+/// grows from 1 to `max_size` over the run. At the first disagreement the input is shrunk:
+/// `gen` draws up to `SHRINK_TRIES` more inputs at each smaller size, from 1 up, and the
+/// failure reported is the first one found at the smallest size that still fails.
+pub fn differential<I, O>(
+    cases: usize,
+    max_size: usize,
+    seed: u64,
+    mut gen: impl FnMut(&mut Rng, usize) -> I,
+    fast: impl Fn(&I) -> O,
+    naive: impl Fn(&I) -> O,
+) -> Result<()>
+where
+    I: Debug,
+    O: PartialEq + Debug,
+{
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let size = (1 + case * max_size / cases.max(1)).min(max_size);
+        let input = gen(&mut rng, size);
+        let (got, want) = (fast(&input), naive(&input));
+        if got != want {
+            let smaller = (1..size).find_map(|smaller| {
+                (0..SHRINK_TRIES).find_map(|_| {
+                    let input = gen(&mut rng, smaller);
+                    let (got, want) = (fast(&input), naive(&input));
+                    (got != want).then_some((smaller, input, got, want))
+                })
+            });
+            let (shrunk, input, got, want) = smaller.unwrap_or((size, input, got, want));
+            bail!(
+                "disagrees on {:?}\n  fast:  {:?}\n  naive: {:?}\n(size {}; first seen at size {} in case {} of {}, seed {})",
+                input,
+                got,
+                want,
+                shrunk,
+                size,
+                case + 1,
+                cases,
+                seed
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrinks_to_the_smallest_disagreement() {
+        // size n draws from 0..=n, so 7 is the only failure at size 7, the smallest that fails;
+        // with 20 cases the sizes grow by 2, and the first failure is usually bigger
+        let gen = |rng: &mut Rng, size: usize| rng.range(0, size as i64);
+        differential(500, 40, 1, gen, |&x| x / 2 * 2 + x % 2, |&x| x).unwrap();
+
+        let err = differential(20, 40, 1, gen, |&x| if x < 7 { x } else { 0 }, |&x| x).unwrap_err().to_string();
+        assert!(err.contains("disagrees on ") && err.contains("naive: "), "{}", err);
+        let input: i64 = err.split("disagrees on ").nth(1).unwrap().lines().next().unwrap().parse().unwrap();
+        assert_eq!(7, input, "{}", err);
+        assert!(err.ends_with("(size 7; first seen at size 11 in case 6 of 20, seed 1)"), "{}", err);
+    }
+}
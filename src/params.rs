@@ -0,0 +1,166 @@
+//! Puzzle constants a day lets you change, such as how many pairs Day 8 connects.
+//!
+//! Each day declares its constants as [`Solution::Params`](crate::Solution::Params), whose
+//! `Default` is the puzzle's values. A run starts from those and applies, in order: the
+//! example's own values from `examples/params.toml` when running an example, the day's table
+//! in a `--config` file, and `--param NAME=VALUE` flags.
+
+use crate::answers;
+use crate::fixtures;
+use crate::input::InputSpec;
+use anyhow::{anyhow, bail, Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+pub const PARAMS_FILE: &str = "params.toml";
+
+/// Parameter values by name, before they are checked against a day's parameters.
+pub type Overrides = toml::Table;
+
+/// The parameters of a day that has none.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Reads a parameters file: one table per input, keyed like `answers.toml`.
+///
+/// ```toml
+/// [08]
+/// pairs = 10
+/// ```
+pub fn load(path: &Path) -> Result<BTreeMap<String, Overrides>> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// Splits `NAME=VALUE`. The value is read as TOML (`10`, `true`, `["a", "b"]`), and as a
+/// plain string if it is not valid TOML.
+pub fn assignment(s: &str) -> Result<(String, toml::Value)> {
+    let (name, value) = s.split_once('=').ok_or_else(|| anyhow!("expected NAME=VALUE, got '{}'", s))?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() {
+        bail!("missing parameter name in '{}'", s);
+    }
+    let value = match toml::from_str::<Overrides>(&format!("v = {}", value)) {
+        Ok(mut t) => t.remove("v").unwrap(),
+        Err(_) => toml::Value::String(value.to_string()),
+    };
+    Ok((name.to_string(), value))
+}
+
+/// Everything that changes `day`'s parameters for one run, lowest precedence first: the
+/// example's values, the `config` file's table for the day and then for its variant, and
+/// the `NAME=VALUE` assignments.
+pub fn overrides(day: u8, input: &InputSpec, config: Option<&Path>, assignments: &[String]) -> Result<Overrides> {
+    let mut out = Overrides::new();
+    if let (None, Some(n)) = (&input.path, input.example) {
+        out.extend(example(&answers::key(day, (n > 1).then(|| n.to_string()).as_deref()))?);
+    }
+    if let Some(path) = config {
+        let mut file = load(path)?;
+        let mut keys = vec![answers::key(day, None)];
+        keys.extend(input.variant.as_deref().map(|v| answers::key(day, Some(v))));
+        for key in keys {
+            out.extend(file.remove(&key).unwrap_or_default());
+        }
+    }
+    for a in assignments {
+        let (name, value) = assignment(a)?;
+        out.insert(name, value);
+    }
+    Ok(out)
+}
+
+/// The values an example uses where they differ from the real puzzle's, by its key in
+/// `examples/answers.toml`.
+pub fn example(key: &str) -> Result<Overrides> {
+    let path = fixtures::examples_dir().join(PARAMS_FILE);
+    if !path.is_file() {
+        return Ok(Overrides::new());
+    }
+    Ok(load(&path)?.remove(key).unwrap_or_default())
+}
+
+/// A day's parameters: the defaults with `overrides` applied. A name the day does not have
+/// is an error listing the ones it does.
+pub fn resolve<P: Default + Serialize + DeserializeOwned>(day: u8, overrides: &Overrides) -> Result<P> {
+    let mut table = Overrides::try_from(P::default()).context("parameters must serialize to a table")?;
+    for (name, value) in overrides {
+        let value = match table.get(name) {
+            None if table.is_empty() => bail!("day {:02} has no parameters, got '{}'", day, name),
+            None => {
+                let known: Vec<String> = table.iter().map(|(k, v)| format!("{} (default {})", k, v)).collect();
+                bail!("day {:02} has no parameter '{}'; it has {}", day, name, known.join(", "))
+            }
+            // names are strings even where they look like numbers
+            Some(toml::Value::String(_)) if !value.is_str() => toml::Value::String(value.to_string()),
+            Some(_) => value.clone(),
+        };
+        table.insert(name.clone(), value);
+    }
+    table.try_into().map_err(|e| anyhow!("invalid parameters for day {:02}: {}", day, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Dial {
+        size: i64,
+        name: String,
+    }
+
+    impl Default for Dial {
+        fn default() -> Self {
+            Dial { size: 100, name: "safe".to_string() }
+        }
+    }
+
+    fn table(assignments: &[&str]) -> Overrides {
+        assignments.iter().map(|a| assignment(a).unwrap()).collect()
+    }
+
+    #[test]
+    fn assignments() {
+        assert_eq!(("pairs".to_string(), toml::Value::Integer(10)), assignment("pairs=10").unwrap());
+        assert_eq!(toml::Value::String("svr".to_string()), assignment("from = svr").unwrap().1);
+        assert_eq!(toml::Value::String("a b".to_string()), assignment("x=\"a b\"").unwrap().1);
+        assert!(assignment("pairs").is_err());
+        assert!(assignment("=3").is_err());
+    }
+
+    #[test]
+    fn resolving() {
+        assert_eq!(Dial::default(), resolve::<Dial>(1, &Overrides::new()).unwrap());
+        let dial: Dial = resolve(1, &table(&["size=7", "name=42"])).unwrap();
+        assert_eq!(Dial { size: 7, name: "42".to_string() }, dial);
+
+        let err = resolve::<Dial>(1, &table(&["sise=7"])).unwrap_err().to_string();
+        assert!(err.contains("no parameter 'sise'") && err.contains("size (default 100)"), "{}", err);
+        assert!(resolve::<Dial>(1, &table(&["size=big"])).is_err());
+        assert!(resolve::<NoParams>(2, &table(&["size=7"])).unwrap_err().to_string().contains("has no parameters"));
+    }
+
+    #[test]
+    fn layers() {
+        let dir = std::env::temp_dir().join(format!("aoc-params-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config = dir.join(PARAMS_FILE);
+        fs::write(&config, "[08]\npairs = 20\nother = 1\n\n[08-big]\npairs = 30\n").unwrap();
+        let spec = |variant: Option<&str>, example| InputSpec { variant: variant.map(String::from), example, path: None };
+
+        let get = |o: Overrides, name: &str| o.get(name).and_then(|v| v.as_integer());
+        assert_eq!(Some(10), get(overrides(8, &spec(None, Some(1)), None, &[]).unwrap(), "pairs"));
+        assert_eq!(Some(20), get(overrides(8, &spec(None, Some(1)), Some(&config), &[]).unwrap(), "pairs"));
+        let variant = overrides(8, &spec(Some("big"), None), Some(&config), &[]).unwrap();
+        assert_eq!((Some(30), Some(1)), (get(variant.clone(), "pairs"), get(variant, "other")));
+        let flag = overrides(8, &spec(Some("big"), None), Some(&config), &["pairs=5".to_string()]).unwrap();
+        assert_eq!(Some(5), get(flag, "pairs"));
+        assert!(overrides(3, &spec(None, None), Some(&config), &[]).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
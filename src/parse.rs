@@ -0,0 +1,131 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A malformed puzzle input, pinned to the offending text.
+///
+/// Renders like a compiler diagnostic:
+///
+/// ```text
+/// invalid digit found in string
+///  --> input/05.txt:3:4
+///   |
+/// 3 | 12-1x4
+///   |    ^^^
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Where the input came from, filled in by the runner once it is known.
+    pub file: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// How many characters to underline; zero points just past the end of a line.
+    pub width: usize,
+    /// The full text of the offending line.
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `span`, which should be a slice of `input` (as produced by `lines`,
+    /// `split`, `trim` and friends). A span that does not point into `input` is looked up
+    /// by value instead, and failing that the error points at the end of the input.
+    pub fn at(input: &str, span: &str, message: impl Display) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o + span.len() <= input.len() && input.is_char_boundary(o))
+            .or_else(|| input.find(span).filter(|_| !span.is_empty()))
+            .unwrap_or(input.len());
+        let width = input[offset..offset + span.len().min(input.len() - offset)].chars().count();
+        Self::at_offset(input, offset, width, message)
+    }
+
+    /// An error `width` characters wide starting at byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, width: usize, message: impl Display) -> Self {
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        let source_line = input[line_start..line_end].trim_end_matches('\r').to_string();
+        ParseError {
+            file: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            width,
+            source_line,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        let file = self.file.as_deref().unwrap_or("<input>");
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{:>w$}--> {}:{}:{}", "", file, self.line, self.column, w = gutter)?;
+        writeln!(f, "{:>w$} |", "", w = gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{:>w$} | {}{}", "", " ".repeat(self.column - 1), "^".repeat(self.width.max(1)), w = gutter)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `field`, a slice of `input`, reporting failures at its position.
+pub fn field<T>(input: &str, field: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    field.parse().map_err(|e| ParseError::at(input, field, format!("invalid value `{}`: {}", field, e)))
+}
+
+/// Checks that every character of `input` is one of `allowed`, ignoring trailing whitespace.
+pub fn expect_chars(input: &str, allowed: &str) -> Result<(), ParseError> {
+    for line in input.lines() {
+        if let Some((i, ch)) = line.trim_end().char_indices().find(|&(_, ch)| !allowed.contains(ch)) {
+            let bad = &line[i..i + ch.len_utf8()];
+            return Err(ParseError::at(input, bad, format!("unexpected character `{}`", ch)));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_offending_span() {
+        let input = "3-5\n10-1x4\n";
+        let bad = &input.lines().nth(1).unwrap()[3..];
+        let err = field::<i64>(input, bad).unwrap_err().with_file("input/05.txt");
+        assert_eq!((2, 4, 3), (err.line, err.column, err.width));
+        assert_eq!(
+            "invalid value `1x4`: invalid digit found in string\n --> input/05.txt:2:4\n  |\n2 | 10-1x4\n  |    ^^^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn missing_text_points_past_the_line() {
+        let input = "a,b\nc";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[line.len()..], "expected `,`");
+        assert_eq!((2, 2, 0), (err.line, err.column, err.width));
+        assert!(err.to_string().ends_with("2 | c\n  |  ^"));
+    }
+
+    #[test]
+    fn unexpected_characters() {
+        assert!(expect_chars("..@\n@.@  \n", ".@").is_ok());
+        let err = expect_chars("..@\n.é#\n", ".@").unwrap_err();
+        assert_eq!((2, 2, 1), (err.line, err.column, err.width));
+    }
+}
@@ -0,0 +1,322 @@
+use crate::cli::{self, DayArgs, Verbosity};
+use crate::input::{self, Input};
+use crate::normalize::normalize;
+use crate::params::{self, Overrides};
+use crate::{start_day, Answer, ParseError, Solution};
+use anyhow::{anyhow, bail, Result};
+use serde::Serialize;
+use std::env;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Answers for one day, already rendered, together with how long each stage took.
+/// A part that was not run has no answer, and only answers that name a mode or have
+/// details have those.
+#[derive(Clone, Debug, Default)]
+pub struct DayResult {
+    pub day: u8,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub part1_mode: Option<String>,
+    pub part2_mode: Option<String>,
+    pub part1_details: Option<serde_json::Value>,
+    pub part2_details: Option<serde_json::Value>,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+/// How to run a day, besides its input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunOptions {
+    /// Run only this part; `None` runs every part.
+    pub part: Option<u8>,
+    /// Parameters that differ from the puzzle's.
+    pub params: Overrides,
+}
+
+impl RunOptions {
+    pub fn part(part: u8) -> Self {
+        RunOptions { part: Some(part), ..RunOptions::default() }
+    }
+}
+
+/// Type-erased entry point for a day, so days with different `Solution` types fit in one table.
+pub type Runner = fn(&str, &RunOptions) -> Result<DayResult>;
+
+pub fn solve<S: Solution>(input: &str, options: &RunOptions) -> Result<DayResult> {
+    let part = options.part;
+    match part {
+        None | Some(1) => {}
+        Some(2) if S::HAS_PART2 => {}
+        Some(2) => bail!("day {:02} has no part 2", S::DAY),
+        Some(p) => bail!("there is no part {}", p),
+    }
+    let runs = |p: u8| part.is_none_or(|only| only == p);
+
+    let params: S::Params = params::resolve(S::DAY, &options.params)?;
+    let start = Instant::now();
+    let parsed = S::parse_with(&normalize(input, S::NORMALIZE), &params)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = if runs(1) { Some(S::part1(&parsed)?) } else { None };
+    let part1_time = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = if S::HAS_PART2 && runs(2) { Some(S::part2(&parsed)?) } else { None };
+    let part2_time = start.elapsed();
+
+    Ok(DayResult {
+        day: S::DAY,
+        part1: part1.as_ref().map(|a| a.to_string()),
+        part2: part2.as_ref().map(|a| a.to_string()),
+        part1_mode: part1.as_ref().and_then(|a| a.mode()),
+        part2_mode: part2.as_ref().and_then(|a| a.mode()),
+        part1_details: part1.as_ref().and_then(|a| a.details()),
+        part2_details: part2.as_ref().and_then(|a| a.details()),
+        parse_time,
+        part1_time,
+        part2_time,
+    })
+}
+
+/// An answer as the text output shows it: followed by its mode, if it has one.
+fn labelled(answer: &str, mode: &Option<String>) -> String {
+    match mode {
+        Some(mode) => format!("{} ({})", answer, mode),
+        None => answer.to_string(),
+    }
+}
+
+/// Prints the answers of every part that ran: under banners, or alone in quiet mode.
+pub fn print_result(res: &DayResult, time: bool) {
+    let parts = [(1, &res.part1, &res.part1_mode, res.part1_time), (2, &res.part2, &res.part2_mode, res.part2_time)];
+    let parts = parts.iter().filter_map(|&(n, answer, mode, took)| answer.as_ref().map(|a| (n, labelled(a, mode), took)));
+    if cli::verbosity() == Verbosity::Quiet {
+        for (_, answer, _) in parts {
+            println!("{}", answer);
+        }
+        return;
+    }
+    for (i, (n, answer, took)) in parts.enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("=== Part {} ===", n);
+        println!("Result = {}", answer);
+        match (time, i) {
+            (true, 0) => println!("took {:?} (parse {:?}).", took, res.parse_time),
+            (true, _) => println!("took {:?}.", took),
+            _ => {}
+        }
+    }
+}
+
+/// How [`run_day`] prints its outcome.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Banners and `Result = N` lines; errors are left to the caller.
+    #[default]
+    Text,
+    /// One [`Report`] per day as a single line of JSON, errors included.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("unknown format '{}', expected text or json", s),
+        }
+    }
+}
+
+/// Machine-readable outcome of one day. Answers are strings, since some do not fit in the
+/// integers JSON readers handle exactly, and timings are in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub input: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub mode: Option<Modes>,
+    pub details: Option<Details>,
+    pub timings: Option<Timings>,
+    pub error: Option<String>,
+}
+
+/// How each answer was computed, for days whose answers name a mode.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Modes {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// What each answer adds beyond its value, for days whose answers have more to them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Details {
+    pub part1: Option<serde_json::Value>,
+    pub part2: Option<serde_json::Value>,
+}
+
+/// Parts that did not run have no timing.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    pub parse_ns: u64,
+    pub part1_ns: Option<u64>,
+    pub part2_ns: Option<u64>,
+}
+
+impl Report {
+    pub fn new(day: u8, input: Option<String>, outcome: &Result<DayResult>) -> Self {
+        let ns = |d: Duration| d.as_nanos() as u64;
+        match outcome {
+            Ok(res) => Report {
+                day,
+                input,
+                part1: res.part1.clone(),
+                part2: res.part2.clone(),
+                mode: (res.part1_mode.is_some() || res.part2_mode.is_some())
+                    .then(|| Modes { part1: res.part1_mode.clone(), part2: res.part2_mode.clone() }),
+                details: (res.part1_details.is_some() || res.part2_details.is_some())
+                    .then(|| Details { part1: res.part1_details.clone(), part2: res.part2_details.clone() }),
+                timings: Some(Timings {
+                    parse_ns: ns(res.parse_time),
+                    part1_ns: res.part1.as_ref().map(|_| ns(res.part1_time)),
+                    part2_ns: res.part2.as_ref().map(|_| ns(res.part2_time)),
+                }),
+                error: None,
+            },
+            Err(e) => Report { day, input, part1: None, part2: None, mode: None, details: None, timings: None, error: Some(format!("{:#}", e)) },
+        }
+    }
+}
+
+/// Runs one day as `args` asks and prints the outcome. In text mode errors are left to the
+/// caller; in JSON mode they are part of the report.
+pub fn run_day(day: u8, runner: Runner, args: &DayArgs) -> Result<DayResult> {
+    let text = args.format == Format::Text;
+    if text && cli::verbosity() != Verbosity::Quiet {
+        start_day(&day.to_string());
+    }
+    let (origin, outcome) = match input::resolve(day, &args.input) {
+        Ok(input) => {
+            if text && cli::verbosity() == Verbosity::Verbose {
+                println!("input: {}", input.origin);
+            }
+            let outcome = args.run_options(day).and_then(|options| run_input(runner, &input, &options));
+            (Some(input.origin.clone()), outcome)
+        }
+        Err(e) => (None, Err(e)),
+    };
+    match args.format {
+        Format::Text => {
+            if let Ok(res) = &outcome {
+                print_result(res, args.time);
+            }
+        }
+        Format::Json => println!("{}", serde_json::to_string(&Report::new(day, origin, &outcome))?),
+    }
+    outcome
+}
+
+/// Runs a day on resolved input, naming the input file in any parse error.
+pub fn run_input(runner: Runner, input: &Input, options: &RunOptions) -> Result<DayResult> {
+    runner(&input.text, options).map_err(|e| match e.downcast::<ParseError>() {
+        Ok(pe) => pe.with_file(input.origin.clone()).into(),
+        Err(e) => e,
+    })
+}
+
+/// `main` for a single-day binary, taking the flags described in [`cli::help`].
+pub fn run_main<S: Solution>() -> Result<()> {
+    let Some(args) = DayArgs::parse(env::args().skip(1))? else {
+        println!("{}", cli::help(S::DAY));
+        return Ok(());
+    };
+    cli::set_verbosity(args.verbosity);
+    run_day(S::DAY, solve::<S>, &args)?;
+    Ok(())
+}
+
+/// Parses a day selection: `3`, `1..=12`, `1..13` or a comma-separated list of those.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>> {
+    let mut days = Vec::new();
+    for part in spec.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        if let Some((a, b)) = part.split_once("..=") {
+            days.extend(parse_day(a)?..=parse_day(b)?);
+        } else if let Some((a, b)) = part.split_once("..") {
+            days.extend(parse_day(a)?..parse_day(b)?);
+        } else {
+            days.push(parse_day(part)?);
+        }
+    }
+    if days.is_empty() {
+        bail!("no days selected by '{}'", spec);
+    }
+    Ok(days)
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    s.trim().parse().map_err(|_| anyhow!("invalid day '{}'", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_specs() {
+        assert_eq!(vec![3], parse_days("3").unwrap());
+        assert_eq!((1..=12).collect::<Vec<u8>>(), parse_days("1..=12").unwrap());
+        assert_eq!(vec![1, 2, 5], parse_days("1..3,5").unwrap());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("").is_err());
+    }
+
+    #[test]
+    fn json_reports() {
+        let res = DayResult {
+            day: 12,
+            part1: Some("2".to_string()),
+            part2: None,
+            part1_mode: None,
+            part2_mode: None,
+            part1_details: None,
+            part2_details: None,
+            parse_time: Duration::from_micros(3),
+            part1_time: Duration::from_nanos(40),
+            part2_time: Duration::ZERO,
+        };
+        let ok = Report::new(12, Some("input/12.txt".to_string()), &Ok(res));
+        assert_eq!(
+            r#"{"day":12,"input":"input/12.txt","part1":"2","part2":null,"mode":null,"details":null,"timings":{"parse_ns":3000,"part1_ns":40,"part2_ns":null},"error":null}"#,
+            serde_json::to_string(&ok).unwrap()
+        );
+        let failed = Report::new(3, None, &Err(anyhow!("no input found for day 03")));
+        assert_eq!(Some("no input found for day 03".to_string()), failed.error);
+        assert_eq!((None, None), (failed.part1, failed.timings));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn answers_name_their_mode() {
+        let res = DayResult {
+            day: 11,
+            part1: Some("5".to_string()),
+            part2: Some("2".to_string()),
+            part1_mode: Some("exact".to_string()),
+            part2_mode: Some("mod 3".to_string()),
+            ..DayResult::default()
+        };
+        assert_eq!("5 (exact)", labelled(res.part1.as_ref().unwrap(), &res.part1_mode));
+        assert_eq!("2 (mod 3)", labelled(res.part2.as_ref().unwrap(), &res.part2_mode));
+        assert_eq!("7", labelled("7", &None));
+        let json = serde_json::to_string(&Report::new(11, None, &Ok(res))).unwrap();
+        assert!(json.contains(r#""part1":"5","part2":"2","mode":{"part1":"exact","part2":"mod 3"}"#), "{}", json);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::answers::ANSWERS_FILE;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs");
+
+/// The source tree the `aoc` binary was built from.
+pub fn crate_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{DD}}", &format!("{:02}", day)).replace("{{DAY}}", &day.to_string())
+}
+
+fn check_day(day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25, got {}", day);
+    }
+    Ok(())
+}
+
+/// Creates a new day under `root`: the solver module `src/days/dayDD.rs` with a test stub,
+/// its registration in `src/days/mod.rs`, the wrapper `src/bin/DD.rs`, an empty
+/// `input/DD.txt`, and an empty example fixture with a placeholder in the example answers.
+/// Nothing is touched if any of the day's files already exist.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    check_day(day)?;
+    let dd = format!("{:02}", day);
+    let module = root.join("src/days").join(format!("day{}.rs", dd));
+    let wrapper = root.join("src/bin").join(format!("{}.rs", dd));
+    let input = root.join("input").join(format!("{}.txt", dd));
+    let example = root.join("examples").join(format!("{}.txt", dd));
+    let existing: Vec<String> =
+        [&module, &wrapper, &input, &example].iter().filter(|p| p.exists()).map(|p| p.display().to_string()).collect();
+    if !existing.is_empty() {
+        bail!("day {} already exists:\n  {}", dd, existing.join("\n  "));
+    }
+
+    let mod_rs = root.join("src/days/mod.rs");
+    let registry = fs::read_to_string(&mod_rs).with_context(|| format!("reading {}", mod_rs.display()))?;
+    let registry = register(&registry, day)?;
+
+    for dir in ["src/days", "src/bin", "input", "examples"] {
+        fs::create_dir_all(root.join(dir))?;
+    }
+    fs::write(&module, render(DAY_TEMPLATE, day))?;
+    fs::write(&mod_rs, registry)?;
+    fs::write(&wrapper, render(BIN_TEMPLATE, day))?;
+    fs::write(&input, "")?;
+    fs::write(&example, "")?;
+    let answers = root.join("examples").join(ANSWERS_FILE);
+    let mut expected = fs::read_to_string(&answers).unwrap_or_default();
+    if !expected.is_empty() && !expected.ends_with("\n\n") {
+        expected.push('\n');
+    }
+    expected.push_str(&format!("[{}]\n# part1 = \"\"\n# part2 = \"\"\n", dd));
+    fs::write(&answers, expected)?;
+    Ok(vec![module, mod_rs, wrapper, input, example, answers])
+}
+
+/// Rewrites `src/bin/DD.rs` from the current template for a day whose solver already exists.
+pub fn regenerate_wrapper(root: &Path, day: u8) -> Result<PathBuf> {
+    check_day(day)?;
+    let module = root.join("src/days").join(format!("day{:02}.rs", day));
+    if !module.is_file() {
+        bail!("day {:02} has no solver module at {}", day, module.display());
+    }
+    let wrapper = root.join("src/bin").join(format!("{:02}.rs", day));
+    fs::write(&wrapper, render(BIN_TEMPLATE, day)).with_context(|| format!("writing {}", wrapper.display()))?;
+    Ok(wrapper)
+}
+
+/// Adds `pub mod dayDD;` and the day's `ALL` entry to `src/days/mod.rs`, keeping both
+/// in day order.
+fn register(registry: &str, day: u8) -> Result<String> {
+    let dd = format!("{:02}", day);
+    let mut lines: Vec<String> = registry.lines().map(|l| l.to_string()).collect();
+    insert_in_order(&mut lines, format!("pub mod day{};", dd), day, |l| {
+        l.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok()
+    })?;
+    insert_in_order(&mut lines, format!("    ({}, solve::<day{}::Day{}>),", day, dd, dd), day, |l| {
+        l.trim().strip_prefix('(')?.split(',').next()?.parse().ok()
+    })?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `line` among the lines `day_of` recognises, before the first one for a later day.
+fn insert_in_order(lines: &mut Vec<String>, line: String, day: u8, day_of: impl Fn(&str) -> Option<u8>) -> Result<()> {
+    let existing: Vec<(usize, u8)> = lines.iter().enumerate().filter_map(|(i, l)| day_of(l).map(|d| (i, d))).collect();
+    let Some(&(last, _)) = existing.last() else { bail!("could not find where to add `{}`", line.trim()) };
+    let at = existing.iter().find(|&&(_, d)| d > day).map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, line);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_in_day_order() {
+        let registry = "pub mod day01;\npub mod day03;\n\npub const ALL: &[(u8, Runner)] = &[\n    (1, solve::<day01::Day01>),\n    (3, solve::<day03::Day03>),\n];\n";
+        let out = register(registry, 2).unwrap();
+        assert_eq!(
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\npub const ALL: &[(u8, Runner)] = &[\n    (1, solve::<day01::Day01>),\n    (2, solve::<day02::Day02>),\n    (3, solve::<day03::Day03>),\n];\n",
+            out
+        );
+        assert!(register(registry, 4).unwrap().contains("(3, solve::<day03::Day03>),\n    (4, solve::<day04::Day04>),\n];"));
+    }
+
+    #[test]
+    fn scaffolds_and_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), "pub mod day01;\n\npub const ALL: &[(u8, Runner)] = &[\n    (1, solve::<day01::Day01>),\n];\n").unwrap();
+
+        let created = new_day(&root, 13).unwrap();
+        assert_eq!(6, created.len());
+        let module = fs::read_to_string(root.join("src/days/day13.rs")).unwrap();
+        assert!(module.contains("pub struct Day13;") && module.contains("const DAY: u8 = 13;"));
+        assert!(fs::read_to_string(root.join("src/bin/13.rs")).unwrap().contains("run_main::<Day13>()"));
+        assert!(fs::read_to_string(root.join("src/days/mod.rs")).unwrap().contains("pub mod day13;"));
+        assert!(fs::read_to_string(root.join("examples/answers.toml")).unwrap().starts_with("[13]\n"));
+
+        assert!(new_day(&root, 13).unwrap_err().to_string().contains("already exists"));
+        fs::write(root.join("src/bin/13.rs"), "stale").unwrap();
+        regenerate_wrapper(&root, 13).unwrap();
+        assert!(fs::read_to_string(root.join("src/bin/13.rs")).unwrap().contains("run_main::<Day13>()"));
+        assert!(regenerate_wrapper(&root, 14).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
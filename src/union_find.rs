@@ -0,0 +1,140 @@
+/// Disjoint sets over `0..n`, with union by size and path compression.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+/// A saved state of a [`UnionFind`], see [`UnionFind::snapshot`].
+#[derive(Clone, Debug)]
+pub struct Snapshot(UnionFind);
+
+impl UnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n).collect(), size: vec![1; n], components: n }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of `a`'s set.
+    pub fn find(&mut self, mut a: usize) -> usize {
+        while self.parent[a] != a {
+            // path halving
+            let pa = self.parent[a];
+            self.parent[a] = self.parent[pa];
+            a = pa;
+        }
+        a
+    }
+
+    /// Merges the sets of `a` and `b`; false if they were already one set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Size of the set containing `a`.
+    pub fn size(&mut self, a: usize) -> usize {
+        let r = self.find(a);
+        self.size[r]
+    }
+
+    /// The size of every set, ordered by each set's smallest member.
+    pub fn component_sizes(&mut self) -> Vec<usize> {
+        let mut seen = vec![false; self.len()];
+        let mut sizes = Vec::with_capacity(self.components);
+        for i in 0..self.len() {
+            let r = self.find(i);
+            if !seen[r] {
+                seen[r] = true;
+                sizes.push(self.size[r]);
+            }
+        }
+        sizes
+    }
+
+    /// The members of every set, ascending, ordered by each set's smallest member.
+    pub fn groups(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut slot = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for i in 0..self.len() {
+            let r = self.find(i);
+            if slot[r] == usize::MAX {
+                slot[r] = groups.len();
+                groups.push(Vec::with_capacity(self.size[r]));
+            }
+            groups[slot[r]].push(i);
+        }
+        groups.into_iter()
+    }
+
+    /// Saves the current sets so later unions can be undone with [`UnionFind::restore`].
+    /// This copies the whole structure, O(n).
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.clone())
+    }
+
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.clone_from(&snapshot.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unions_and_components() {
+        let mut uf = UnionFind::new(6);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(4, 1));
+        assert!(!uf.union(0, 4));
+        assert!(uf.union(2, 5));
+        assert_eq!(3, uf.components());
+        assert_eq!(3, uf.size(4));
+        assert!(uf.same(2, 5) && !uf.same(2, 3));
+        assert_eq!(vec![3, 2, 1], uf.component_sizes());
+        assert_eq!(vec![vec![0, 1, 4], vec![2, 5], vec![3]], uf.groups().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn snapshot_restores_earlier_sets() {
+        let mut uf = UnionFind::new(4);
+        uf.union(0, 1);
+        let saved = uf.snapshot();
+        uf.union(1, 2);
+        uf.union(2, 3);
+        assert_eq!(1, uf.components());
+        uf.restore(&saved);
+        assert_eq!(3, uf.components());
+        assert!(uf.same(0, 1) && !uf.same(1, 2));
+        assert_eq!(vec![2, 1, 1], uf.component_sizes());
+    }
+}
@@ -0,0 +1,6 @@
+use adv_code_2025::days::day{{DD}}::Day{{DD}};
+use adv_code_2025::*;
+
+fn main() -> anyhow::Result<()> {
+    run_main::<Day{{DD}}>()
+}
@@ -0,0 +1,39 @@
+use crate::parse::ParseError;
+use crate::{NoParams, Solution};
+use anyhow::Result;
+
+pub struct Day{{DD}};
+
+impl Solution for Day{{DD}} {
+    const DAY: u8 = {{DAY}};
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 1 of the puzzle
+        Ok(input.len())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize> {
+        // TODO: Solve Part 2 of the puzzle
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn examples() {
+        fixtures::check_examples::<Day{{DD}}>().unwrap();
+    }
+}